@@ -0,0 +1,111 @@
+//! Pre-built workloads for benchmarking [`Approximint`].
+//!
+//! These workloads are intended to be timed by any benchmarking harness,
+//! allowing this crate to be compared against other large number libraries
+//! using the same operations.
+//!
+//! ```rust
+//! use std::time::Instant;
+//!
+//! for workload in approximint::bench::baseline_ops() {
+//!     let start = Instant::now();
+//!     std::hint::black_box(workload.run());
+//!     println!("{}: {:?}", workload.name, start.elapsed());
+//! }
+//! ```
+
+use core::hint::black_box;
+
+use crate::{Approximint, DisplayBuffer, FormatOptions, Notation};
+
+/// The number of operations performed by each workload.
+pub const OPERATIONS: u32 = 1_000_000;
+
+/// A named benchmark workload.
+#[derive(Clone, Copy, Debug)]
+pub struct Workload {
+    /// The name of this workload.
+    pub name: &'static str,
+    run: fn() -> Approximint,
+}
+
+impl Workload {
+    /// Runs this workload and returns its result.
+    ///
+    /// The result should be passed to a function such as [`black_box`] to
+    /// prevent the workload from being optimized away.
+    #[must_use]
+    #[inline]
+    pub fn run(&self) -> Approximint {
+        (self.run)()
+    }
+}
+
+/// Returns the baseline workloads.
+///
+/// Each workload performs [`OPERATIONS`] operations:
+///
+/// - `add_exponent_gaps`: Adds values whose exponents differ by varying
+///   amounts, including gaps large enough to discard an operand entirely.
+/// - `mul_chain`: Multiplies a running product by alternating large and
+///   small factors.
+/// - `format`: Formats values of increasing magnitude using each
+///   [`Notation`] into a [`DisplayBuffer`].
+#[must_use]
+pub const fn baseline_ops() -> [Workload; 3] {
+    [
+        Workload {
+            name: "add_exponent_gaps",
+            run: add_exponent_gaps,
+        },
+        Workload {
+            name: "mul_chain",
+            run: mul_chain,
+        },
+        Workload {
+            name: "format",
+            run: format,
+        },
+    ]
+}
+
+fn add_exponent_gaps() -> Approximint {
+    let mut total = Approximint::ZERO;
+    for i in 0..OPERATIONS {
+        total = total.const_add(black_box(
+            Approximint::new(123_456_789).with_added_ten_power(i % 40),
+        ));
+    }
+    total
+}
+
+fn mul_chain() -> Approximint {
+    let large = Approximint::new(123_456_789).with_added_ten_power(1_000);
+    let small = Approximint::new(3);
+    let mut product = Approximint::ONE;
+    for i in 0..OPERATIONS {
+        let factor = if i % 2 == 0 { large } else { small };
+        product = black_box(product * factor);
+        if product == Approximint::MAX {
+            product = Approximint::ONE;
+        }
+    }
+    product
+}
+
+fn format() -> Approximint {
+    const NOTATIONS: [Notation; 4] = [
+        Notation::Automatic,
+        Notation::Decimal,
+        Notation::Scientific,
+        Notation::English,
+    ];
+    let mut buffer = DisplayBuffer::<64>::new();
+    let mut length = 0_u64;
+    for i in 0..OPERATIONS {
+        let options = FormatOptions::new().notation(NOTATIONS[(i % 4) as usize]);
+        let value = Approximint::new(987_654_321).with_added_ten_power(i % 30);
+        length += buffer.write(black_box(value), &options).len() as u64;
+    }
+    Approximint::approximate(length)
+}
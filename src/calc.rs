@@ -0,0 +1,331 @@
+use core::cmp::Ordering;
+
+use crate::Approximint;
+
+/// The largest coefficient a [`Calculation`] keeps before discarding the
+/// least significant digits.
+const WIDE_LIMIT: i128 = 10_i128.pow(27);
+
+/// The largest coefficient kept when multiplying two calculations together.
+const NARROW_LIMIT: i128 = 10_i128.pow(19);
+
+/// A chain of arithmetic operations evaluated with a widened intermediate
+/// value.
+///
+/// Each operation on an [`Approximint`] rounds its result to 9 digits of
+/// precision. When evaluating formulas with many steps, these small errors
+/// can accumulate. A `Calculation` instead keeps 27 digits of precision and an
+/// unrestricted exponent between operations, and only rounds to an
+/// [`Approximint`] when [`finish`](Self::finish) is called.
+///
+/// ```rust
+/// use approximint::Approximint;
+///
+/// let base = Approximint::new(1_000);
+/// let total = base
+///     .calc()
+///     .mul(Approximint::new(3))
+///     .add(Approximint::new(7))
+///     .finish();
+/// assert_eq!(total, Approximint::new(3_007));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct Calculation {
+    coefficient: i128,
+    ten_power: i64,
+}
+
+impl Calculation {
+    /// Returns a new calculation starting with `value`.
+    #[inline]
+    pub fn new(value: Approximint) -> Self {
+        Self {
+            coefficient: i128::from(value.coefficient),
+            ten_power: i64::from(value.ten_power),
+        }
+    }
+
+    /// Returns a calculation representing `coefficient * 10^ten_power`.
+    pub(crate) fn from_parts(coefficient: i128, ten_power: i64) -> Self {
+        Self {
+            coefficient,
+            ten_power,
+        }
+        .reduced()
+    }
+
+    /// Adds `value` to the current result.
+    #[expect(clippy::should_implement_trait)]
+    pub fn add(self, value: Approximint) -> Self {
+        let rhs = Self::new(value);
+        let (lhs, rhs) = if self.ten_power >= rhs.ten_power {
+            Self::align(self, rhs)
+        } else {
+            let (rhs, lhs) = Self::align(rhs, self);
+            (lhs, rhs)
+        };
+        Self {
+            coefficient: lhs.coefficient + rhs.coefficient,
+            ten_power: lhs.ten_power,
+        }
+        .reduced()
+    }
+
+    /// Subtracts `value` from the current result.
+    #[inline]
+    #[expect(clippy::should_implement_trait)]
+    pub fn sub(self, value: Approximint) -> Self {
+        self.add(-value)
+    }
+
+    /// Multiplies the current result by `value`.
+    #[expect(clippy::should_implement_trait)]
+    pub fn mul(self, value: Approximint) -> Self {
+        Self {
+            coefficient: self.coefficient * i128::from(value.coefficient),
+            ten_power: self.ten_power + i64::from(value.ten_power),
+        }
+        .reduced()
+    }
+
+    /// Multiplies the current result by `value`.
+    ///
+    /// `value` is converted using 17 significant digits. Multiplying by an
+    /// infinite value saturates the result, and multiplying by NaN produces
+    /// zero.
+    #[cfg(feature = "std")]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn mul_f64(self, value: f64) -> Self {
+        if value.is_nan() || value == 0. {
+            return Self::new(Approximint::ZERO);
+        } else if value.is_infinite() {
+            return Self {
+                coefficient: self.coefficient * if value < 0. { -1 } else { 1 },
+                ten_power: i64::MAX / 2,
+            };
+        }
+
+        let exponent = value.abs().log10().floor() as i32 - 17;
+        // Powers of ten up to 1e22 are exactly representable, so scaling
+        // using multiplication when possible avoids introducing error.
+        let scaled = if exponent < 0 {
+            value * 10f64.powi(-exponent)
+        } else {
+            value / 10f64.powi(exponent)
+        };
+        let coefficient = scaled.round() as i128;
+        // The coefficient has 18 digits, so the current result is narrowed to
+        // prevent the product from overflowing.
+        let this = self.narrowed();
+        Self {
+            coefficient: this.coefficient * coefficient,
+            ten_power: this.ten_power + i64::from(exponent),
+        }
+        .reduced()
+    }
+
+    /// Returns the current result raised to the `exponent` power.
+    ///
+    /// Intermediate products keep 19 digits of precision.
+    pub(crate) fn raised(self, mut exponent: u32) -> Self {
+        let mut base = self;
+        let mut result = Self::new(Approximint::ONE);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_narrow(base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul_narrow(base);
+            }
+        }
+        result
+    }
+
+    /// Returns the `n`th root of the magnitude of the current result, rounded
+    /// to the nearest [`Approximint`].
+    pub(crate) fn root(self, n: u32) -> Approximint {
+        let magnitude = self.coefficient.unsigned_abs();
+        if magnitude == 0 {
+            return Approximint::ZERO;
+        }
+
+        // The root has `order / n` integer digits. The root is searched for
+        // with 10 significant digits so that `finish()` can round correctly.
+        let order = i64::from(magnitude.ilog10()) + self.ten_power;
+        let ten_power = order.div_euclid(i64::from(n)) - 9;
+        let (mut low, mut high) = (1_000_000_000_i128, 9_999_999_999_i128);
+        while low < high {
+            let middle = (low + high + 1) / 2;
+            let raised = Self::from_parts(middle, ten_power).raised(n);
+            if raised.cmp_magnitude(self) == Ordering::Greater {
+                high = middle - 1;
+            } else {
+                low = middle;
+            }
+        }
+
+        Self::from_parts(low, ten_power).finish()
+    }
+
+    /// Multiplies two calculations after reducing both to 19 digits so that
+    /// the product can't overflow.
+    fn mul_narrow(self, rhs: Self) -> Self {
+        let lhs = self.narrowed();
+        let rhs = rhs.narrowed();
+        Self {
+            coefficient: lhs.coefficient * rhs.coefficient,
+            ten_power: lhs.ten_power + rhs.ten_power,
+        }
+        .reduced()
+    }
+
+    fn narrowed(mut self) -> Self {
+        while self.coefficient.abs() >= NARROW_LIMIT {
+            self.coefficient /= 10;
+            self.ten_power += 1;
+        }
+        self
+    }
+
+    /// Compares the absolute values of two calculations.
+    pub(crate) fn cmp_magnitude(self, other: Self) -> Ordering {
+        let (lhs, rhs) = (
+            self.coefficient.unsigned_abs(),
+            other.coefficient.unsigned_abs(),
+        );
+        match (lhs, rhs) {
+            (0, 0) => return Ordering::Equal,
+            (0, _) => return Ordering::Less,
+            (_, 0) => return Ordering::Greater,
+            _ => {}
+        }
+        let lhs_order = i64::from(lhs.ilog10()) + self.ten_power;
+        let rhs_order = i64::from(rhs.ilog10()) + other.ten_power;
+        lhs_order.cmp(&rhs_order).then_with(|| {
+            // Both values have the same number of integer digits, so
+            // scaling the value with the larger ten power to match the other
+            // can't exceed the number of digits a calculation keeps.
+            let shift = |value: u128, places: i64| {
+                value * 10_u128.pow(u32::try_from(places).expect("checked by order"))
+            };
+            if self.ten_power >= other.ten_power {
+                shift(lhs, self.ten_power - other.ten_power).cmp(&rhs)
+            } else {
+                lhs.cmp(&shift(rhs, other.ten_power - self.ten_power))
+            }
+        })
+    }
+
+    /// Returns the current result divided by `divisor`, keeping at least 18
+    /// digits of precision.
+    pub(crate) fn divided(self, divisor: u64) -> Self {
+        // Reduced coefficients are less than 1e27, leaving room for 10 more
+        // digits before dividing.
+        Self {
+            coefficient: self.coefficient * 10_i128.pow(10) / i128::from(divisor),
+            ten_power: self.ten_power - 10,
+        }
+        .reduced()
+    }
+
+    /// Returns the current result multiplied by `10^exponent`.
+    pub(crate) fn scaled(mut self, exponent: i64) -> Self {
+        self.ten_power += exponent;
+        self
+    }
+
+    /// Rounds the result of this calculation to the nearest [`Approximint`].
+    ///
+    /// Results that are too large to be represented saturate to
+    /// [`Approximint::MAX`] or [`Approximint::MIN`].
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn finish(self) -> Approximint {
+        if self.coefficient == 0 {
+            return Approximint::ZERO;
+        }
+
+        let mut digits = 0_i64;
+        let mut remaining = self.coefficient;
+        while remaining != 0 {
+            digits += 1;
+            remaining /= 10;
+        }
+        let places = (digits - 9).max(-self.ten_power).max(0);
+        let mut coefficient = round_shift(self.coefficient, places);
+        let mut ten_power = self.ten_power + places;
+        if coefficient.abs() >= i128::from(Approximint::COEFFICIENT_LIMIT) {
+            coefficient /= 10;
+            ten_power += 1;
+        }
+
+        if ten_power > i64::from(u32::MAX) {
+            if coefficient < 0 {
+                Approximint::MIN
+            } else {
+                Approximint::MAX
+            }
+        } else {
+            Approximint {
+                coefficient: coefficient as i32,
+                ten_power: ten_power as u32,
+            }
+            .normalized()
+        }
+    }
+
+    /// Returns `(higher, lower)` with both values using the same ten power,
+    /// preserving as much precision of `higher` as possible.
+    fn align(mut higher: Self, mut lower: Self) -> (Self, Self) {
+        while higher.ten_power > lower.ten_power && higher.coefficient.abs() < WIDE_LIMIT {
+            higher.coefficient *= 10;
+            higher.ten_power -= 1;
+        }
+        let difference = higher.ten_power - lower.ten_power;
+        lower.coefficient = if difference > 38 {
+            0
+        } else {
+            lower.coefficient / 10_i128.pow(u32::try_from(difference).expect("checked above"))
+        };
+        lower.ten_power = higher.ten_power;
+        (higher, lower)
+    }
+
+    fn reduced(mut self) -> Self {
+        while self.coefficient.abs() >= WIDE_LIMIT {
+            self.coefficient /= 10;
+            self.ten_power += 1;
+        }
+        if self.coefficient == 0 {
+            self.ten_power = 0;
+        }
+        self
+    }
+}
+
+/// Divides `value` by `10^places`, rounding half away from zero.
+fn round_shift(value: i128, places: i64) -> i128 {
+    if places == 0 {
+        return value;
+    } else if places > 38 {
+        return 0;
+    }
+
+    let divisor = 10_i128.pow(u32::try_from(places).expect("checked above"));
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    if remainder.abs() * 2 >= divisor {
+        quotient + value.signum()
+    } else {
+        quotient
+    }
+}
+
+impl From<Approximint> for Calculation {
+    #[inline]
+    fn from(value: Approximint) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,153 @@
+//! Exact comparisons between [`Approximint`]s and floating point numbers.
+
+use core::cmp::Ordering;
+
+use crate::Approximint;
+
+/// Compares `value` to the exact value of `other`, returning `None` if
+/// `other` is NaN.
+pub fn cmp_f64(value: Approximint, other: f64) -> Option<Ordering> {
+    if other.is_nan() {
+        return None;
+    } else if other.is_infinite() {
+        return Some(if other > 0. {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        });
+    }
+
+    let value_sign = value.coefficient.cmp(&0);
+    let other_sign = if other == 0. {
+        Ordering::Equal
+    } else if other < 0. {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    if value_sign != other_sign || value_sign == Ordering::Equal {
+        return Some(value_sign.cmp(&other_sign));
+    }
+
+    let magnitude = cmp_magnitude(
+        value.coefficient.unsigned_abs(),
+        value.ten_power,
+        other.abs(),
+    );
+    Some(if value_sign == Ordering::Less {
+        magnitude.reverse()
+    } else {
+        magnitude
+    })
+}
+
+/// Compares `coefficient * 10^ten_power` to `other`, which must be finite and
+/// positive.
+fn cmp_magnitude(coefficient: u32, ten_power: u32, other: f64) -> Ordering {
+    let (mantissa, exponent) = decode(other);
+
+    // Estimate the number of bits in each value. 10^ten_power has between
+    // floor(ten_power * log2(10)) and one more than that many bits beyond the
+    // coefficient's bits, and the estimate of log2(10) may be off by one.
+    let ten_bits = i64::from(ten_power) * 3_321_928_095 / 1_000_000_000;
+    let value_bits = i64::from(u32::BITS - coefficient.leading_zeros()) + ten_bits;
+    let other_bits = i64::from(u64::BITS - mantissa.leading_zeros()) + exponent;
+    if value_bits > other_bits + 2 {
+        return Ordering::Greater;
+    } else if value_bits + 2 < other_bits {
+        return Ordering::Less;
+    }
+
+    // The values are close enough that they must be compared exactly:
+    // coefficient * 5^ten_power * 2^ten_power vs mantissa * 2^exponent.
+    let mut value = Wide::from(u64::from(coefficient));
+    for _ in 0..ten_power {
+        value.mul_small(5);
+    }
+    let mut other = Wide::from(mantissa);
+    let shift = i64::from(ten_power) - exponent;
+    if shift >= 0 {
+        value.shl(shift.unsigned_abs());
+    } else {
+        other.shl(shift.unsigned_abs());
+    }
+    value.cmp(&other)
+}
+
+/// Returns `mantissa` and `exponent` such that `value = mantissa *
+/// 2^exponent`. `value` must be finite.
+fn decode(value: f64) -> (u64, i64) {
+    let bits = value.to_bits();
+    let biased_exponent = i64::try_from((bits >> 52) & 0x7ff).expect("11 bits");
+    let fraction = bits & ((1 << 52) - 1);
+    if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    }
+}
+
+/// The number of 32-bit limbs in a [`Wide`] integer.
+const LIMBS: usize = 40;
+
+/// A fixed-size unsigned integer large enough for exactly comparing values
+/// near the range of `f64`.
+#[derive(Eq, PartialEq)]
+struct Wide {
+    /// The limbs of this integer, from least to most significant.
+    limbs: [u32; LIMBS],
+}
+
+#[expect(clippy::cast_possible_truncation)]
+impl Wide {
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        debug_assert_eq!(carry, 0, "wide integer overflow");
+    }
+
+    fn shl(&mut self, bits: u64) {
+        let limbs = usize::try_from(bits / 32).expect("shift too large");
+        let bits = (bits % 32) as u32;
+        debug_assert!(
+            self.limbs[LIMBS - limbs..].iter().all(|limb| *limb == 0),
+            "wide integer overflow"
+        );
+        self.limbs.copy_within(..LIMBS - limbs, limbs);
+        self.limbs[..limbs].fill(0);
+        if bits > 0 {
+            let mut carry = 0;
+            for limb in &mut self.limbs {
+                let shifted = (u64::from(*limb) << bits) | carry;
+                *limb = shifted as u32;
+                carry = shifted >> 32;
+            }
+        }
+    }
+}
+
+impl From<u64> for Wide {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        Self { limbs }
+    }
+}
+
+impl Ord for Wide {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl PartialOrd for Wide {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,97 @@
+use core::fmt::Display;
+
+use crate::Approximint;
+
+/// An error converting an [`Approximint`] into a primitive integer.
+///
+/// ```rust
+/// use approximint::{Approximint, TryFromApproximintError};
+///
+/// assert_eq!(u64::try_from(Approximint::new(42)), Ok(42));
+/// assert_eq!(
+///     u64::try_from(Approximint::new(-42)),
+///     Err(TryFromApproximintError::OutOfRange)
+/// );
+/// assert_eq!(u64::try_from(Approximint::one_e(12)), Ok(1_000_000_000_000));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum TryFromApproximintError {
+    /// The value is outside of the range of the integer type.
+    OutOfRange,
+}
+
+impl Display for TryFromApproximintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            TryFromApproximintError::OutOfRange => "out of range",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromApproximintError {}
+
+/// An error converting a float into an [`Approximint`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum TryFromFloatError {
+    /// The float was `NaN`.
+    NotANumber,
+    /// The float was positive or negative infinity.
+    Infinite,
+}
+
+impl Display for TryFromFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            TryFromFloatError::NotANumber => "not a number",
+            TryFromFloatError::Infinite => "infinite value",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromFloatError {}
+
+impl Approximint {
+    /// Returns `|coefficient| * 10^ten_power`, or `None` if it does not fit
+    /// in a `u128`.
+    fn checked_magnitude(self) -> Option<u128> {
+        10_u128
+            .checked_pow(self.ten_power)?
+            .checked_mul(u128::from(self.coefficient.unsigned_abs()))
+    }
+}
+
+macro_rules! impl_try_into_integer {
+    ($($int:ty),+) => {
+        $(
+            /// Converts to the exact integer value.
+            ///
+            /// Values whose magnitude exceeds the integer's range return
+            /// [`TryFromApproximintError::OutOfRange`]. The trailing zeroes of
+            /// values stored with a ten power are converted as zeroes,
+            /// matching the integer comparisons.
+            impl TryFrom<Approximint> for $int {
+                type Error = TryFromApproximintError;
+
+                fn try_from(value: Approximint) -> Result<Self, Self::Error> {
+                    value
+                        .checked_magnitude()
+                        .and_then(|magnitude| {
+                            if value.coefficient < 0 {
+                                let magnitude = i128::try_from(magnitude).ok()?;
+                                <$int>::try_from(-magnitude).ok()
+                            } else {
+                                <$int>::try_from(magnitude).ok()
+                            }
+                        })
+                        .ok_or(TryFromApproximintError::OutOfRange)
+                }
+            }
+        )+
+    };
+}
+
+impl_try_into_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
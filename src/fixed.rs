@@ -0,0 +1,239 @@
+use core::fmt::{Display, Write};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::fmt::write_padded;
+use crate::{Approximint, Calculation, FormatOptions};
+
+/// A fixed-point companion to [`Approximint`] with `DECIMALS` decimal
+/// digits.
+///
+/// This type stores its value as an [`Approximint`] counting units of
+/// `10^-DECIMALS`. Values are exact while the number of units fits in the
+/// coefficient of an [`Approximint`], and are approximated beyond that. For
+/// example, an `Approxfixed<2>` tracks exact cents for values up to
+/// `9,999,999.99`, and grows just like an [`Approximint`] above that.
+///
+/// `DECIMALS` must be less than or equal to 8.
+///
+/// ```rust
+/// use approximint::{Approxfixed, Approximint};
+///
+/// let price = Approxfixed::<2>::from_parts(12, 34);
+/// let total = price * 3 + Approxfixed::new(Approximint::new(1));
+/// assert_eq!(total.to_string(), "38.02");
+/// assert_eq!(total.to_approximint(), Approximint::new(38));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Hash, Debug)]
+pub struct Approxfixed<const DECIMALS: u8> {
+    units: Approximint,
+}
+
+impl<const DECIMALS: u8> Approxfixed<DECIMALS> {
+    /// A value of zero.
+    pub const ZERO: Self = Self::from_units(Approximint::ZERO);
+
+    const EXPONENT: u32 = {
+        assert!(DECIMALS <= 8, "DECIMALS must be less than or equal to 8");
+        DECIMALS as u32
+    };
+    const SCALE: u32 = 10_u32.pow(Self::EXPONENT);
+
+    /// Returns `whole` as a fixed-point value.
+    #[must_use]
+    #[inline]
+    pub const fn new(whole: Approximint) -> Self {
+        Self::from_units(whole.with_added_ten_power(Self::EXPONENT))
+    }
+
+    /// Returns `whole + fraction / 10^DECIMALS`.
+    ///
+    /// The sign of `whole` is applied to the fraction, so
+    /// `from_parts(-1, 50)` is `-1.50`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fraction` is greater than or equal to `10^DECIMALS`.
+    #[must_use]
+    #[inline]
+    pub fn from_parts(whole: i32, fraction: u32) -> Self {
+        assert!(fraction < Self::SCALE, "fraction out of range");
+        let whole_units = i128::from(whole) * i128::from(Self::SCALE);
+        let units = if whole < 0 {
+            whole_units - i128::from(fraction)
+        } else {
+            whole_units + i128::from(fraction)
+        };
+        Self::from_units(Calculation::from_parts(units, 0).finish())
+    }
+
+    /// Returns a value containing `units` units of `10^-DECIMALS`.
+    #[must_use]
+    #[inline]
+    pub const fn from_units(units: Approximint) -> Self {
+        Self { units }
+    }
+
+    /// Returns the number of `10^-DECIMALS` units in this value.
+    #[must_use]
+    #[inline]
+    pub const fn units(self) -> Approximint {
+        self.units
+    }
+
+    /// Returns true if this value is stored exactly.
+    #[must_use]
+    #[inline]
+    pub const fn is_exact(self) -> bool {
+        self.units.ten_power == 0
+    }
+
+    /// Returns the whole portion of this value, truncating any fractional
+    /// digits.
+    #[must_use]
+    #[inline]
+    pub fn to_approximint(self) -> Approximint {
+        self.units / Approximint::one_e(Self::EXPONENT)
+    }
+
+    /// Returns a [`Display`] implementor that formats this value using
+    /// `options`.
+    ///
+    /// Exact values are displayed with all `DECIMALS` decimal digits, using
+    /// the separator and decimal characters from `options`. All other values
+    /// are formatted identically to [`FormatOptions::format`].
+    #[inline]
+    pub const fn format(self, options: FormatOptions) -> FormattedFixed<DECIMALS> {
+        FormattedFixed {
+            value: self,
+            options,
+        }
+    }
+}
+
+impl<const DECIMALS: u8> From<Approximint> for Approxfixed<DECIMALS> {
+    #[inline]
+    fn from(whole: Approximint) -> Self {
+        Self::new(whole)
+    }
+}
+
+impl<const DECIMALS: u8> From<Approxfixed<DECIMALS>> for Approximint {
+    #[inline]
+    fn from(value: Approxfixed<DECIMALS>) -> Self {
+        value.to_approximint()
+    }
+}
+
+impl<const DECIMALS: u8> Neg for Approxfixed<DECIMALS> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_units(-self.units)
+    }
+}
+
+impl<const DECIMALS: u8> Add for Approxfixed<DECIMALS> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_units(self.units + rhs.units)
+    }
+}
+
+impl<const DECIMALS: u8> AddAssign for Approxfixed<DECIMALS> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const DECIMALS: u8> Sub for Approxfixed<DECIMALS> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_units(self.units - rhs.units)
+    }
+}
+
+impl<const DECIMALS: u8> SubAssign for Approxfixed<DECIMALS> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const DECIMALS: u8> Mul<i32> for Approxfixed<DECIMALS> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::from_units(self.units * rhs)
+    }
+}
+
+impl<const DECIMALS: u8> MulAssign<i32> for Approxfixed<DECIMALS> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const DECIMALS: u8> Display for Approxfixed<DECIMALS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.format(FormatOptions::new()), f)
+    }
+}
+
+/// A [`Display`] implementor that formats an [`Approxfixed`] using
+/// [`FormatOptions`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct FormattedFixed<const DECIMALS: u8> {
+    value: Approxfixed<DECIMALS>,
+    options: FormatOptions,
+}
+
+impl<const DECIMALS: u8> Display for FormattedFixed<DECIMALS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut this = *self;
+        this.options.plus_sign |= f.sign_plus();
+        write_padded(f, |f| this.write_unpadded(f))
+    }
+}
+
+impl<const DECIMALS: u8> FormattedFixed<DECIMALS> {
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.value.is_exact() {
+            return Display::fmt(&self.options.format(self.value.to_approximint()), f);
+        }
+
+        let units = self.value.units.coefficient;
+        let (negative_prefix, negative_suffix) = self.options.negative_affixes.unwrap_or(("-", ""));
+        if units < 0 {
+            f.write_str(negative_prefix)?;
+        } else if self.options.plus_sign && units > 0 {
+            f.write_char('+')?;
+        }
+        let scale = Approxfixed::<DECIMALS>::SCALE;
+        let whole = units.unsigned_abs() / scale;
+        let fraction = units.unsigned_abs() % scale;
+        #[expect(clippy::cast_possible_wrap)]
+        Display::fmt(
+            &self
+                .options
+                .decimal_formatter(Approximint::new(whole as i32)),
+            f,
+        )?;
+        if DECIMALS > 0 {
+            f.write_char(self.options.scientific.decimal)?;
+            write!(f, "{fraction:0width$}", width = usize::from(DECIMALS))?;
+        }
+        if units < 0 {
+            f.write_str(negative_suffix)?;
+        }
+        Ok(())
+    }
+}
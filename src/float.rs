@@ -0,0 +1,110 @@
+//! Floating point support for builds without the standard library.
+//!
+//! `core` does not provide functions such as `f64::log10` or `f64::powi`, so
+//! these implementations operate directly on the IEEE 754 representation.
+
+use crate::Approximint;
+
+/// Approximates `value` by extracting its binary mantissa and exponent.
+///
+/// The result is within one unit of the ninth significant digit of the exact
+/// value. `NaN` approximates to zero, and infinities saturate.
+pub fn approximate(value: f64) -> Approximint {
+    if value.is_nan() {
+        return Approximint::ZERO;
+    } else if value.is_infinite() {
+        return if value < 0. {
+            Approximint::MIN
+        } else {
+            Approximint::MAX
+        };
+    }
+
+    let bits = value.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased_exponent = i32::try_from((bits >> 52) & 0x7ff).expect("11 bits");
+    let fraction = bits & ((1 << 52) - 1);
+    // value = mantissa * 2^exponent
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    let magnitude = if exponent < 0 {
+        let shift = exponent.unsigned_abs();
+        if shift > 53 {
+            Approximint::ZERO
+        } else {
+            // Round half up while shifting the fractional bits away.
+            let integer = (u128::from(mantissa) + (1 << (shift - 1))) >> shift;
+            rounded(integer, 0)
+        }
+    } else {
+        let mut mantissa = u128::from(mantissa);
+        let mut remaining = exponent.unsigned_abs();
+        let mut ten_power = 0;
+        while remaining > 0 {
+            // Keep 4 bits of headroom so that the next shift can't overflow.
+            let shift = remaining.min(mantissa.leading_zeros().saturating_sub(4));
+            if shift == 0 {
+                mantissa /= 10;
+                ten_power += 1;
+            } else {
+                mantissa <<= shift;
+                remaining -= shift;
+            }
+        }
+        rounded(mantissa, ten_power)
+    };
+
+    if negative {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Returns `mantissa * 10^ten_power` rounded to nine significant digits.
+#[expect(clippy::cast_possible_truncation)]
+fn rounded(mut mantissa: u128, mut ten_power: u32) -> Approximint {
+    let limit = u128::from(Approximint::COEFFICIENT_LIMIT.unsigned_abs());
+    let mut round_up = false;
+    while mantissa >= limit {
+        round_up = mantissa % 10 >= 5;
+        mantissa /= 10;
+        ten_power += 1;
+    }
+    if round_up {
+        mantissa += 1;
+        if mantissa == limit {
+            mantissa /= 10;
+            ten_power += 1;
+        }
+    }
+
+    Approximint {
+        coefficient: mantissa as i32,
+        ten_power,
+    }
+    .normalize_underflow()
+}
+
+/// Returns `base` raised to the `exponent` power using repeated squaring.
+pub fn powi(mut base: f64, exponent: i32) -> f64 {
+    let mut remaining = exponent.unsigned_abs();
+    let mut result = 1.;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        remaining >>= 1;
+    }
+
+    if exponent < 0 {
+        1. / result
+    } else {
+        result
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt::Write;
+
+use crate::fmt::FormatOptions;
+use crate::Approximint;
+
+/// A fixed-capacity buffer that formats [`Approximint`]s without allocating.
+///
+/// This type is intended to be reused, such as formatting values every frame
+/// of a game's user interface. Each call to [`DisplayBuffer::write`] replaces
+/// the previous contents of the buffer.
+///
+/// If a formatted value does not fit in `N` bytes, the output is truncated at
+/// the last character that fits.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct DisplayBuffer<const N: usize = 64> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> DisplayBuffer<N> {
+    /// Returns a new, empty buffer.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Formats `value` using `options`, replacing the contents of this
+    /// buffer, and returns the formatted text.
+    pub fn write(&mut self, value: Approximint, options: &FormatOptions) -> &str {
+        self.len = 0;
+        // An error is only returned when the output was truncated.
+        let _ = write!(self, "{}", options.format(value));
+        self.as_str()
+    }
+
+    /// Returns the current contents of this buffer.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).expect("only valid utf-8 is written")
+    }
+}
+
+impl<const N: usize> Default for DisplayBuffer<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for DisplayBuffer<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let remaining = N - self.len;
+        if s.len() <= remaining {
+            self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        } else {
+            let mut fits = remaining;
+            while !s.is_char_boundary(fits) {
+                fits -= 1;
+            }
+            self.bytes[self.len..self.len + fits].copy_from_slice(&s.as_bytes()[..fits]);
+            self.len += fits;
+            Err(core::fmt::Error)
+        }
+    }
+}
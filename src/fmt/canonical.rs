@@ -0,0 +1,43 @@
+use core::fmt::Display;
+
+use crate::fmt::common::write_padded;
+use crate::Approximint;
+
+/// A [`Display`] implementor for an [`Approximint`] that formats its exact
+/// coefficient and exponent.
+///
+/// The output is `{coefficient}e{exponent}`, such as `-123456789e42`, and is
+/// not affected by any formatting options. It can be parsed using
+/// [`Approximint::from_canonical_str`], which always produces the original
+/// value.
+///
+/// ```rust
+/// use approximint::Approximint;
+///
+/// let value = Approximint::new(-123_456_789) * Approximint::one_e(42);
+/// let canonical = value.as_canonical().to_string();
+/// assert_eq!(canonical, "-123456789e42");
+/// assert_eq!(Approximint::from_canonical_str(&canonical), Ok(value));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct CanonicalFormatter(Approximint);
+
+impl From<Approximint> for CanonicalFormatter {
+    #[inline]
+    fn from(num: Approximint) -> Self {
+        Self(num)
+    }
+}
+
+impl Display for CanonicalFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_padded(f, |f| self.write_unpadded(f))
+    }
+}
+
+impl CanonicalFormatter {
+    fn write_unpadded(self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}e{}", self.0.coefficient, self.0.ten_power)
+    }
+}
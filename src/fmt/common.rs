@@ -0,0 +1,398 @@
+//! Helpers shared by the formatters.
+
+use core::fmt::{Alignment, Display, Formatter, Write};
+use core::slice;
+
+use crate::fmt::{LoneDigit, ScientificSettings};
+use crate::Approximint;
+
+/// Writes the output of `write` to `f`, honoring the width, fill, and
+/// alignment flags of `f`.
+///
+/// `write` is always called with a formatter whose width, precision, and sign
+/// flags are unset, ensuring the flags of `f` are only applied once.
+///
+/// The output is written twice when a width is requested: once to measure
+/// its length, and once to `f`. Like the integer types, values are aligned
+/// to the right unless another alignment is requested.
+pub(crate) fn write_padded(
+    f: &mut Formatter<'_>,
+    write: impl Fn(&mut Formatter<'_>) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let contents = Unpadded(write);
+    let Some(width) = f.width() else {
+        return if f.precision().is_some() || f.sign_plus() {
+            // Nested formatters have already been configured using the
+            // precision and sign.
+            write!(f, "{contents}")
+        } else {
+            (contents.0)(f)
+        };
+    };
+
+    let mut length = CharCount(0);
+    write!(length, "{contents}")?;
+    let padding = width.saturating_sub(length.0);
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    // Writing through `write!` ensures nested formatters don't see the
+    // width again.
+    write!(f, "{contents}")?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// A [`Display`] implementor that invokes a function using a formatter with
+/// no flags set.
+struct Unpadded<F>(F);
+
+impl<F> Display for Unpadded<F>
+where
+    F: Fn(&mut Formatter<'_>) -> core::fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// A [`Write`] implementor that counts the characters written to it.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// The digits and exponent of a number with up to `N` digits of precision.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ScientificInfo<const N: usize = 9> {
+    pub(crate) digits: DigitRing<N>,
+    pub(crate) exponent: u64,
+    pub(crate) negative: bool,
+}
+
+impl ScientificInfo {
+    pub(crate) fn new(num: Approximint) -> Self {
+        Self::from_parts(
+            num.coefficient < 0,
+            u64::from(num.coefficient.unsigned_abs()),
+            num.ten_power,
+        )
+    }
+}
+
+impl<const N: usize> ScientificInfo<N> {
+    pub(crate) fn from_parts(negative: bool, mut coefficient: u64, ten_power: u32) -> Self {
+        let mut digits = DigitRing::new();
+        let mut exponent = 0;
+        while coefficient > 0 {
+            digits.push_back((coefficient % 10) as u8 + b'0');
+            coefficient /= 10;
+            exponent += 1;
+        }
+
+        let exponent = exponent - 1 + u64::from(ten_power);
+        Self {
+            digits,
+            exponent,
+            negative,
+        }
+    }
+
+    /// Rounds to `significant_digits` digits using `rounding`.
+    pub(crate) fn round(&mut self, significant_digits: usize, rounding: Rounding) {
+        if significant_digits < N {
+            let discards_digits = || {
+                self.digits
+                    .iter()
+                    .skip(significant_digits)
+                    .any(|digit| digit != b'0')
+            };
+            // A digit of 5 followed by only zeroes is exactly halfway.
+            let halfway_rounds_down = match rounding {
+                Rounding::HalfDown => true,
+                Rounding::HalfEven => significant_digits
+                    .checked_sub(1)
+                    .and_then(|index| self.digits.iter().nth(index))
+                    .map_or(true, |digit| (digit - b'0') % 2 == 0),
+                _ => false,
+            };
+            let lowest_rounded_up = match rounding {
+                Rounding::HalfDown | Rounding::HalfEven
+                    if halfway_rounds_down
+                        && self
+                            .digits
+                            .iter()
+                            .skip(significant_digits + 1)
+                            .all(|digit| digit == b'0') =>
+                {
+                    b'6'
+                }
+                Rounding::HalfUp | Rounding::HalfDown | Rounding::HalfEven => b'5',
+                Rounding::Floor if self.negative && discards_digits() => b'0',
+                Rounding::Ceil if !self.negative && discards_digits() => b'0',
+                Rounding::Floor | Rounding::Ceil | Rounding::TowardZero => return,
+            };
+            let mut digits_to_round = self.digits.iter_mut_rev().skip(N - 1 - significant_digits);
+            let check_digit = digits_to_round.next().expect("not 0");
+            if (lowest_rounded_up..=b'9').contains(check_digit) {
+                let mut carry = false;
+                for digit in digits_to_round {
+                    if *digit == b'9' {
+                        *digit = b'0';
+                        carry = true;
+                    } else {
+                        *digit += 1;
+                        carry = false;
+                        break;
+                    }
+                }
+
+                // If we still have the carry flag, we need to push a new 1
+                // digit.
+                if carry {
+                    self.digits.push_back(b'1');
+                    self.exponent += 1;
+                }
+            }
+        }
+    }
+
+    pub(crate) fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        settings: ScientificSettings,
+    ) -> core::fmt::Result {
+        if self.negative {
+            f.write_char('-')?;
+        }
+        let displayed = self
+            .digits
+            .iter()
+            .take(usize::from(settings.significant_digits));
+        let lone_digit = displayed
+            .clone()
+            .skip(1)
+            .all(|digit| !settings.keep_trailing_zeroes && digit == b'0');
+        let mut digits = displayed.enumerate();
+        while let Some((index, digit)) = digits.next() {
+            if !settings.keep_trailing_zeroes
+                && index > 0
+                && digit == b'0'
+                && digits.clone().all(|(_, digit)| digit == b'0')
+            {
+                break;
+            }
+
+            if index == 1 {
+                f.write_char(settings.decimal)?;
+            }
+
+            f.write_char(char::from(digit))?;
+        }
+
+        if lone_digit {
+            match settings.lone_digit {
+                LoneDigit::Bare => {}
+                LoneDigit::Point => f.write_char(settings.decimal)?,
+                LoneDigit::PointZero => {
+                    f.write_char(settings.decimal)?;
+                    f.write_char('0')?;
+                }
+            }
+        }
+
+        f.write_str(settings.exponent_marker)?;
+        let Some(separator) = settings.exponent_separator else {
+            return write!(f, "{}", self.exponent);
+        };
+
+        let mut digits = [0; 20];
+        let mut first = digits.len();
+        let mut exponent = self.exponent;
+        loop {
+            first -= 1;
+            // The remainder is always less than 10.
+            digits[first] = b'0' + (exponent % 10) as u8;
+            exponent /= 10;
+            if exponent == 0 {
+                break;
+            }
+        }
+        let digits = &digits[first..];
+        let grouping = Grouping::new(separator, &[3], digits.len() - 1);
+        for (index, digit) in digits.iter().enumerate() {
+            grouping.write_before(index, f)?;
+            f.write_char(char::from(*digit))?;
+        }
+        Ok(())
+    }
+}
+
+/// A strategy for rounding a value to fewer digits.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum Rounding {
+    /// Rounds to the nearest value, rounding halfway values away from zero.
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest value, rounding halfway values towards zero.
+    ///
+    /// This strategy never overstates a value that is exactly halfway
+    /// between two displayed values, such as when displaying currency.
+    HalfDown,
+    /// Rounds to the nearest value, rounding halfway values to the nearest
+    /// even digit.
+    ///
+    /// This strategy avoids the bias of always rounding halfway values in
+    /// the same direction.
+    HalfEven,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds towards zero, discarding any extra digits.
+    TowardZero,
+}
+
+/// A ring buffer of up to `N` ASCII digits.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct DigitRing<const N: usize> {
+    digits: [u8; N],
+    first: u8,
+}
+
+impl<const N: usize> DigitRing<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            digits: [0; N],
+            first: 0,
+        }
+    }
+
+    pub(crate) fn push_back(&mut self, digit: u8) {
+        self.digits[usize::from(self.first)] = digit;
+        self.first += 1;
+        if usize::from(self.first) == self.digits.len() {
+            self.first = 0;
+        }
+    }
+
+    pub(crate) fn iter(&self) -> DigitRingIter<'_> {
+        self.into_iter()
+    }
+
+    pub(crate) fn iter_mut_rev(&mut self) -> DigitRingIterMutRev<'_> {
+        let (first, second) = self.digits.split_at_mut(usize::from(self.first));
+        DigitRingIterMutRev(second.iter_mut(), first.iter_mut())
+    }
+
+    pub(crate) const fn len(&self) -> usize {
+        self.digits.len()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a DigitRing<N> {
+    type IntoIter = DigitRingIter<'a>;
+    type Item = u8;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let (first, second) = self.digits.split_at(usize::from(self.first));
+        DigitRingIter(second.iter(), first.iter())
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct DigitRingIter<'a>(slice::Iter<'a, u8>, slice::Iter<'a, u8>);
+
+impl Iterator for DigitRingIter<'_> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let digit = self.1.next_back().or_else(|| self.0.next_back())?;
+            if *digit > 0 {
+                return Some(*digit);
+            }
+        }
+    }
+}
+
+pub(crate) struct DigitRingIterMutRev<'a>(slice::IterMut<'a, u8>, slice::IterMut<'a, u8>);
+
+impl<'a> Iterator for DigitRingIterMutRev<'a> {
+    type Item = &'a mut u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().or_else(|| self.1.next())
+    }
+}
+
+/// Inserts a separator between groups of integer digits.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Grouping<'a> {
+    separator: char,
+    sizes: &'a [u8],
+    exponent: usize,
+}
+
+impl<'a> Grouping<'a> {
+    /// Returns the grouping for a number whose leading digit is at
+    /// `10^exponent`.
+    ///
+    /// `sizes` contains the number of digits in each group, starting with the
+    /// group containing the ones digit. The last size repeats for the
+    /// remaining digits. Grouping stops at the first size of 0.
+    pub(crate) fn new(separator: char, sizes: &'a [u8], exponent: usize) -> Self {
+        Self {
+            separator,
+            sizes,
+            exponent,
+        }
+    }
+
+    /// Writes the separator if one belongs before the integer digit at
+    /// `index`, counting from the leading digit.
+    pub(crate) fn write_before(
+        &self,
+        index: usize,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        if index > 0 && index <= self.exponent && self.is_boundary(self.exponent + 1 - index) {
+            f.write_char(self.separator)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if a group ends after the `trailing_digits` least
+    /// significant digits.
+    fn is_boundary(&self, trailing_digits: usize) -> bool {
+        let mut group_end = 0;
+        for size in self.sizes {
+            if *size == 0 {
+                return false;
+            }
+            group_end += usize::from(*size);
+            if group_end >= trailing_digits {
+                return group_end == trailing_digits;
+            }
+        }
+        match self.sizes.last() {
+            Some(&last) => (trailing_digits - group_end) % usize::from(last) == 0,
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use core::fmt::{Display, Write};
+
+use crate::fmt::common::{write_padded, Grouping, ScientificInfo};
+use crate::fmt::{Locale, Rounding, ScientificFormatter, WordFormatter};
+use crate::Approximint;
+
+/// A [`Display`] implementor for an [`Approximint`] that formats using decimal
+/// notation.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct DecimalFormatter {
+    pub(crate) num: Approximint,
+    pub(crate) separator: char,
+    pub(crate) digits_per_separator: u8,
+    pub(crate) plus_sign: bool,
+    group_sizes: Option<&'static [u8]>,
+    pub(crate) scientific_after: Option<u32>,
+    word_fallback: Option<&'static [(u32, &'static str)]>,
+    rounding: Option<Rounding>,
+}
+
+impl DecimalFormatter {
+    /// Sets the character to use between grouped integer digits.
+    ///
+    /// The default separator is `,`.
+    #[inline]
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of integer digits between each separator character.
+    ///
+    /// The default is 3.
+    #[inline]
+    pub fn digits_per_separator(mut self, digits: u8) -> Self {
+        self.digits_per_separator = digits;
+        self.group_sizes = None;
+        self
+    }
+
+    /// Sets the number of integer digits in each group, starting with the
+    /// group containing the ones digit.
+    ///
+    /// The last size is repeated for the remaining digits. For example, `[3,
+    /// 2]` produces the Indian grouping of `12,34,56,789`, and `[4]` groups
+    /// digits by ten-thousands. This replaces
+    /// [`digits_per_separator`](Self::digits_per_separator).
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let num = Approximint::new(123_456_789);
+    /// assert_eq!(num.as_decimal().group_sizes(&[3, 2]).to_string(), "12,34,56,789");
+    /// assert_eq!(num.as_decimal().group_sizes(&[4]).to_string(), "1,2345,6789");
+    /// ```
+    #[inline]
+    pub fn group_sizes(mut self, sizes: &'static [u8]) -> Self {
+        self.group_sizes = Some(sizes);
+        self
+    }
+
+    /// Displays a `+` before positive values.
+    ///
+    /// This can also be enabled using the `+` flag of a format string, such
+    /// as `{:+}`.
+    #[inline]
+    pub fn plus_sign(mut self) -> Self {
+        self.plus_sign = true;
+        self
+    }
+
+    /// Displays values using scientific notation once the power of ten they
+    /// are stored with reaches `ten_power`.
+    ///
+    /// An [`Approximint`] stores up to 9 digits multiplied by a power of ten,
+    /// so values are displayed using scientific notation once they reach
+    /// `10^(ten_power + 8)`. The default is 30, which displays every value
+    /// less than `1e38` in full.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let num = Approximint::new(123_456_789) * Approximint::one_e(3);
+    /// assert_eq!(num.as_decimal().to_string(), "123,456,789,000");
+    /// assert_eq!(num.as_decimal().scientific_after(4).to_string(), "123,456,789,000");
+    /// assert_eq!(num.as_decimal().scientific_after(3).to_string(), "1.234e11");
+    ///
+    /// let largest = Approximint::new(999_999_999) * Approximint::one_e(29);
+    /// assert_eq!(
+    ///     largest.as_decimal().to_string(),
+    ///     "99,999,999,900,000,000,000,000,000,000,000,000,000"
+    /// );
+    /// assert_eq!(Approximint::one_e(38).as_decimal().to_string(), "1.000e38");
+    /// ```
+    #[inline]
+    pub fn scientific_after(mut self, ten_power: u32) -> Self {
+        self.scientific_after = Some(ten_power);
+        self
+    }
+
+    /// Uses the separator and digit grouping of `locale`.
+    #[inline]
+    pub fn locale(self, locale: Locale) -> Self {
+        self.separator(locale.separator)
+            .group_sizes(locale.group_sizes)
+    }
+
+    /// Displays values past the [`scientific_after`](Self::scientific_after)
+    /// threshold using `words` instead of scientific notation.
+    ///
+    /// `words` is a slice of pairs of powers of ten and the associated word,
+    /// in the same form as [`WordFormatter::new`].
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let words = &[(3, "thousand"), (6, "million"), (9, "billion")];
+    /// let formatted =
+    ///     |num: Approximint| num.as_decimal().scientific_after(3).word_fallback(words).to_string();
+    /// assert_eq!(formatted(Approximint::new(123_456_789)), "123,456,789");
+    /// assert_eq!(
+    ///     formatted(Approximint::new(123_456_789) * Approximint::one_e(3)),
+    ///     "123.4 billion"
+    /// );
+    /// ```
+    #[inline]
+    pub fn word_fallback(mut self, words: &'static [(u32, &'static str)]) -> Self {
+        self.word_fallback = Some(words);
+        self
+    }
+
+    /// Rounds the digits displayed using `rounding` when a value is displayed
+    /// using scientific notation or words.
+    ///
+    /// Values displayed in full are always exact. By default, digits that are
+    /// not displayed are truncated.
+    #[inline]
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Displays every value in full, regardless of its magnitude.
+    #[inline]
+    pub fn never_scientific(mut self) -> Self {
+        self.scientific_after = None;
+        self
+    }
+}
+
+impl From<Approximint> for DecimalFormatter {
+    #[inline]
+    fn from(num: Approximint) -> Self {
+        Self {
+            num,
+            separator: ',',
+            digits_per_separator: 3,
+            plus_sign: false,
+            group_sizes: None,
+            scientific_after: Some(30),
+            word_fallback: None,
+            rounding: None,
+        }
+    }
+}
+
+impl Display for DecimalFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let this = Self {
+            plus_sign: self.plus_sign || f.sign_plus(),
+            ..*self
+        };
+        write_padded(f, |f| this.write_unpadded(f))
+    }
+}
+
+impl DecimalFormatter {
+    /// Returns the grouping of integer digits for a number whose leading
+    /// digit is at `10^exponent`.
+    pub(crate) fn grouping(&self, exponent: usize) -> Grouping<'_> {
+        let sizes = self
+            .group_sizes
+            .unwrap_or(core::slice::from_ref(&self.digits_per_separator));
+        Grouping::new(self.separator, sizes, exponent)
+    }
+
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.num.ten_power == 0 && self.num.coefficient == 0 {
+            return f.write_str("0");
+        } else if self
+            .scientific_after
+            .is_some_and(|ten_power| self.num.ten_power >= ten_power)
+        {
+            if let Some(words) = self.word_fallback.filter(|words| !words.is_empty()) {
+                let smallest = words.iter().map(|(power, _)| *power).min();
+                let mut formatter = WordFormatter::new(self.num, words)
+                    .decimal_before_10_power(smallest.unwrap_or(0))
+                    .separator(self.separator)
+                    .digits_per_separator(self.digits_per_separator);
+                if let Some(sizes) = self.group_sizes {
+                    formatter = formatter.group_sizes(sizes);
+                }
+                if self.plus_sign {
+                    formatter = formatter.plus_sign();
+                }
+                if let Some(rounding) = self.rounding {
+                    formatter = formatter.rounding(rounding);
+                }
+                return Display::fmt(&formatter, f);
+            }
+            let mut scientific = ScientificFormatter::from(self.num);
+            if let Some(rounding) = self.rounding {
+                scientific = scientific.rounding(rounding);
+            }
+            return if self.plus_sign {
+                Display::fmt(&scientific.plus_sign(), f)
+            } else {
+                Display::fmt(&scientific, f)
+            };
+        }
+
+        // To avoid allocations, we need to figure out how many total digits we
+        // have so that we can emit separators along the way.
+        let info = ScientificInfo::new(self.num);
+
+        if info.negative {
+            f.write_char('-')?;
+        } else if self.plus_sign {
+            f.write_char('+')?;
+        }
+
+        let exponent_usize = usize::try_from(info.exponent).expect("exponent too large for usize");
+        let grouping = self.grouping(exponent_usize);
+
+        let mut index = 0;
+        for digit in info
+            .digits
+            .iter()
+            .take(info.digits.len().min(exponent_usize + 1))
+        {
+            grouping.write_before(index, f)?;
+            f.write_char(char::from(digit))?;
+            index += 1;
+        }
+
+        for index in index..=exponent_usize {
+            grouping.write_before(index, f)?;
+            f.write_char('0')?;
+        }
+        Ok(())
+    }
+}
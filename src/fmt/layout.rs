@@ -0,0 +1,161 @@
+use std::string::{String, ToString};
+use std::vec::{self, Vec};
+
+use crate::fmt::{FormatOptions, Notation};
+use crate::Approximint;
+
+/// The role of a run of text produced by [`LayoutChunks`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum ChunkRole {
+    /// A leading `-` or `+`, or the affixes written around a negative value,
+    /// such as `(` and `)`.
+    Sign,
+    /// A marker written before a value that may be inexact, such as `~`.
+    Approximate,
+    /// A run of digits.
+    Digits,
+    /// A separator between groups of integer digits.
+    Separator,
+    /// The character between whole and decimal digits.
+    Decimal,
+    /// An exponent marker and its digits, such as `e42`.
+    Exponent,
+    /// A word, such as `million`.
+    Word,
+    /// Whitespace between other chunks.
+    Space,
+}
+
+/// An iterator over the runs of text of a formatted [`Approximint`] and the
+/// role of each run.
+///
+/// This type is returned from [`Approximint::layout_chunks`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct LayoutChunks {
+    text: String,
+    offset: usize,
+    leading: vec::IntoIter<(usize, ChunkRole)>,
+    body_end: usize,
+    separator: Option<char>,
+    decimal: char,
+    exponent_marker: &'static str,
+}
+
+impl LayoutChunks {
+    pub(crate) fn new(num: Approximint, options: &FormatOptions) -> Self {
+        let notation = match options.notation {
+            Notation::Automatic if num.ten_power > 0 => Notation::Scientific,
+            Notation::Automatic => Notation::Decimal,
+            other => other,
+        };
+        let separator = if notation == Notation::Scientific {
+            None
+        } else {
+            Some(options.separator)
+        };
+        let text = options.format(num).to_string();
+
+        // Mirror the order that `Formatted` writes its prefixes in, so that
+        // affixes and markers are never mistaken for parts of the number.
+        let mut leading = Vec::new();
+        let mut body = num;
+        let mut suffix_len = 0;
+        if options.plus_sign && body.is_positive() {
+            leading.push((1, ChunkRole::Sign));
+        }
+        if let (Some((prefix, suffix)), true) = (options.negative_affixes, body.is_negative()) {
+            leading.push((prefix.len(), ChunkRole::Sign));
+            suffix_len = suffix.len();
+            body = -body;
+            if options.plus_sign && body.is_positive() {
+                leading.push((1, ChunkRole::Sign));
+            }
+        }
+        if notation == Notation::Scientific && body.ten_power > 0 {
+            leading.push((
+                options.scientific.approx_marker.len(),
+                ChunkRole::Approximate,
+            ));
+        }
+        if body.is_negative() {
+            leading.push((1, ChunkRole::Sign));
+        }
+        leading.retain(|&(length, _)| length > 0);
+
+        Self {
+            offset: 0,
+            leading: leading.into_iter(),
+            body_end: text.len() - suffix_len,
+            text,
+            separator,
+            decimal: options.scientific.decimal,
+            exponent_marker: options.scientific.exponent_marker,
+        }
+    }
+
+    /// Returns the entire formatted text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Iterator for LayoutChunks {
+    type Item = (String, ChunkRole);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((length, role)) = self.leading.next() {
+            let chunk = String::from(&self.text[self.offset..self.offset + length]);
+            self.offset += length;
+            return Some((chunk, role));
+        }
+        if self.offset >= self.body_end {
+            let chunk = String::from(
+                self.text
+                    .get(self.offset..)
+                    .filter(|suffix| !suffix.is_empty())?,
+            );
+            self.offset = self.text.len();
+            return Some((chunk, ChunkRole::Sign));
+        }
+
+        let remaining = &self.text[self.offset..self.body_end];
+        let first = remaining.chars().next()?;
+        let exponent_digits = (!self.exponent_marker.is_empty())
+            .then(|| remaining.strip_prefix(self.exponent_marker))
+            .flatten()
+            .filter(|digits| digits.starts_with(|ch: char| ch.is_ascii_digit()));
+        let (role, length) = if first.is_ascii_digit() {
+            (
+                ChunkRole::Digits,
+                run_length(remaining, |ch| ch.is_ascii_digit()),
+            )
+        } else if Some(first) == self.separator {
+            (ChunkRole::Separator, first.len_utf8())
+        } else if first == self.decimal {
+            (ChunkRole::Decimal, first.len_utf8())
+        } else if let Some(digits) = exponent_digits {
+            (
+                ChunkRole::Exponent,
+                self.exponent_marker.len() + run_length(digits, |ch| ch.is_ascii_digit()),
+            )
+        } else if first.is_whitespace() {
+            (ChunkRole::Space, run_length(remaining, char::is_whitespace))
+        } else {
+            (
+                ChunkRole::Word,
+                run_length(remaining, |ch| !ch.is_whitespace() && !ch.is_ascii_digit()),
+            )
+        };
+        self.offset += length;
+        Some((String::from(&remaining[..length]), role))
+    }
+}
+
+/// Returns the length in bytes of the leading characters of `text` that
+/// match `predicate`.
+fn run_length(text: &str, predicate: impl Fn(char) -> bool) -> usize {
+    text.find(|ch: char| !predicate(ch)).unwrap_or(text.len())
+}
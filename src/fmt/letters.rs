@@ -0,0 +1,176 @@
+use core::fmt::{Display, Write};
+
+use crate::fmt::common::{write_padded, Rounding, ScientificInfo};
+use crate::fmt::DecimalFormatter;
+use crate::Approximint;
+
+/// A [`Display`] implementor that formats an [`Approximint`] using
+/// alphabetic suffixes, such as `1.23a` or `45.6bz`.
+///
+/// Each suffix represents a power of one thousand. The first suffix is `a`,
+/// followed by `b` through `z`, then `aa`, `ab`, and so on. Because the
+/// suffixes are generated from the exponent, every value has a suffix.
+///
+/// ```rust
+/// use approximint::{Approximint, LetterFormatter};
+///
+/// assert_eq!(LetterFormatter::new(Approximint::new(999)).to_string(), "999");
+/// assert_eq!(LetterFormatter::new(Approximint::new(1_500)).to_string(), "1.5a");
+/// assert_eq!(LetterFormatter::new(Approximint::one_e(81)).to_string(), "1aa");
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct LetterFormatter {
+    num: Approximint,
+    start: u32,
+    decimal_places: u8,
+    rounding: Rounding,
+    plus_sign: bool,
+}
+
+impl LetterFormatter {
+    /// Returns a formatter for `num` whose first suffix, `a`, represents one
+    /// thousand.
+    #[inline]
+    pub const fn new(num: Approximint) -> Self {
+        Self {
+            num,
+            start: 3,
+            decimal_places: 2,
+            rounding: Rounding::HalfDown,
+            plus_sign: false,
+        }
+    }
+
+    /// Sets the power of ten represented by the first suffix, `a`.
+    ///
+    /// Values smaller than `10^ten_power` are displayed using decimal
+    /// notation. The default is 3.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, LetterFormatter};
+    ///
+    /// let formatter = LetterFormatter::new(Approximint::new(123_456_789)).start(6);
+    /// assert_eq!(formatter.to_string(), "123.46a");
+    /// ```
+    #[inline]
+    pub const fn start(mut self, ten_power: u32) -> Self {
+        self.start = ten_power;
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a suffix.
+    ///
+    /// Trailing zeroes are not displayed. The default is 2.
+    #[inline]
+    pub const fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    /// Rounds the displayed digits using `rounding`.
+    ///
+    /// The default is [`Rounding::HalfDown`].
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Displays a `+` before positive values.
+    ///
+    /// This can also be enabled using the `+` flag of a format string, such
+    /// as `{:+}`.
+    #[inline]
+    pub const fn plus_sign(mut self) -> Self {
+        self.plus_sign = true;
+        self
+    }
+
+    /// Returns the number of integer digits displayed before the suffix of a
+    /// value whose leading digit is at `10^exponent`.
+    fn integer_digits(&self, exponent: u64) -> usize {
+        // The remainder is always less than 3.
+        ((exponent - u64::from(self.start)) % 3) as usize + 1
+    }
+}
+
+impl From<Approximint> for LetterFormatter {
+    #[inline]
+    fn from(num: Approximint) -> Self {
+        Self::new(num)
+    }
+}
+
+/// The precision of the format string, such as `{:.3}`, sets the maximum
+/// number of decimal places.
+impl Display for LetterFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut this = *self;
+        if let Some(precision) = f.precision() {
+            this.decimal_places = u8::try_from(precision).unwrap_or(u8::MAX);
+        }
+        this.plus_sign |= f.sign_plus();
+        write_padded(f, |f| this.write_unpadded(f))
+    }
+}
+
+impl LetterFormatter {
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.num == Approximint::ZERO {
+            return f.write_str("0");
+        }
+        let mut info = ScientificInfo::new(self.num);
+        if info.exponent < u64::from(self.start) {
+            let mut decimal = DecimalFormatter::from(self.num);
+            decimal.plus_sign = self.plus_sign;
+            return Display::fmt(&decimal, f);
+        }
+
+        info.round(
+            self.integer_digits(info.exponent)
+                .saturating_add(usize::from(self.decimal_places)),
+            self.rounding,
+        );
+        if info.negative {
+            f.write_char('-')?;
+        } else if self.plus_sign {
+            f.write_char('+')?;
+        }
+
+        let integer_digits = self.integer_digits(info.exponent);
+        let mut digits = info
+            .digits
+            .iter()
+            .chain(core::iter::repeat(b'0'))
+            .take(integer_digits + usize::from(self.decimal_places))
+            .enumerate();
+        while let Some((index, digit)) = digits.next() {
+            if index >= integer_digits {
+                if digit == b'0' && digits.clone().all(|(_, digit)| digit == b'0') {
+                    break;
+                } else if index == integer_digits {
+                    f.write_char('.')?;
+                }
+            }
+            f.write_char(char::from(digit))?;
+        }
+
+        // The suffixes are the bijective base-26 representation of the
+        // suffix's index, where `a` is 1 and `z` is 26.
+        let mut remaining = (info.exponent - u64::from(self.start)) / 3 + 1;
+        let mut letters = [0; 14];
+        let mut length = 0;
+        while remaining > 0 {
+            remaining -= 1;
+            // The remainder is always less than 26.
+            letters[length] = b'a' + (remaining % 26) as u8;
+            remaining /= 26;
+            length += 1;
+        }
+        for letter in letters[..length].iter().rev() {
+            f.write_char(char::from(*letter))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+/// The symbols and digit grouping used to format numbers in a locale.
+///
+/// A locale can be applied to the formatters using their `locale` functions,
+/// such as [`DecimalFormatter::locale`](crate::DecimalFormatter::locale) or
+/// [`FormatOptions::locale`](crate::FormatOptions::locale).
+///
+/// ```rust
+/// use approximint::{Approximint, FormatOptions, Locale, Notation};
+///
+/// let num = Approximint::new(123_456_789);
+/// assert_eq!(num.as_decimal().locale(Locale::de_de()).to_string(), "123.456.789");
+/// assert_eq!(num.as_decimal().locale(Locale::hi_in()).to_string(), "12,34,56,789");
+/// assert_eq!(
+///     FormatOptions::new()
+///         .notation(Notation::Scientific)
+///         .locale(Locale::de_de())
+///         .format(num)
+///         .to_string(),
+///     "1,234E8"
+/// );
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct Locale {
+    pub(crate) decimal: char,
+    pub(crate) separator: char,
+    pub(crate) group_sizes: &'static [u8],
+    pub(crate) exponent_marker: &'static str,
+}
+
+impl Locale {
+    /// Returns a locale using `.` as the decimal point, `,` between groups of
+    /// three digits, and `e` as the exponent marker.
+    ///
+    /// These are the symbols used by the formatters by default.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            decimal: '.',
+            separator: ',',
+            group_sizes: &[3],
+            exponent_marker: "e",
+        }
+    }
+
+    /// Returns the locale for English as used in the United States.
+    #[inline]
+    pub const fn en_us() -> Self {
+        Self::new().exponent_marker("E")
+    }
+
+    /// Returns the locale for German as used in Germany.
+    #[inline]
+    pub const fn de_de() -> Self {
+        Self::en_us().decimal(',').separator('.')
+    }
+
+    /// Returns the locale for French as used in France.
+    ///
+    /// Groups of digits are separated by a narrow no-break space.
+    #[inline]
+    pub const fn fr_fr() -> Self {
+        Self::en_us().decimal(',').separator('\u{202f}')
+    }
+
+    /// Returns the locale for Hindi as used in India.
+    ///
+    /// The three least significant digits are grouped together, and the
+    /// remaining digits are grouped in pairs, such as `12,34,56,789`.
+    #[inline]
+    pub const fn hi_in() -> Self {
+        Self::en_us().group_sizes(&[3, 2])
+    }
+
+    /// Returns the locale for Japanese as used in Japan.
+    #[inline]
+    pub const fn ja_jp() -> Self {
+        Self::en_us()
+    }
+
+    /// Sets the character to use between the whole number and decimal digits.
+    #[inline]
+    pub const fn decimal(mut self, decimal: char) -> Self {
+        self.decimal = decimal;
+        self
+    }
+
+    /// Sets the character to use between grouped integer digits.
+    #[inline]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of integer digits in each group, starting with the
+    /// group containing the ones digit.
+    ///
+    /// See [`DecimalFormatter::group_sizes`](crate::DecimalFormatter::group_sizes)
+    /// for more information.
+    #[inline]
+    pub const fn group_sizes(mut self, sizes: &'static [u8]) -> Self {
+        self.group_sizes = sizes;
+        self
+    }
+
+    /// Sets the text written between the digits and the exponent in
+    /// scientific notation.
+    #[inline]
+    pub const fn exponent_marker(mut self, marker: &'static str) -> Self {
+        self.exponent_marker = marker;
+        self
+    }
+}
+
+impl Default for Locale {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
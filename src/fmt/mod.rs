@@ -0,0 +1,66 @@
+//! Formatting [`Approximint`](crate::Approximint)s.
+//!
+//! Each formatter implements [`Display`](core::fmt::Display) and is
+//! constructed from an [`Approximint`](crate::Approximint), either directly or
+//! through methods such as
+//! [`Approximint::as_scientific`](crate::Approximint::as_scientific). All
+//! formatters are also exported from the crate root.
+//!
+//! The [`prelude`] module re-exports every formatter for convenient glob
+//! imports.
+
+mod buffer;
+mod canonical;
+mod common;
+mod decimal;
+#[cfg(feature = "std")]
+mod layout;
+mod letters;
+mod locale;
+mod options;
+mod relative;
+mod scientific;
+mod smart;
+mod suffix;
+mod words;
+
+pub use buffer::DisplayBuffer;
+pub use canonical::CanonicalFormatter;
+pub(crate) use common::write_padded;
+pub use common::Rounding;
+#[cfg(test)]
+pub(crate) use common::ScientificInfo;
+pub use decimal::DecimalFormatter;
+#[cfg(feature = "std")]
+pub use layout::{ChunkRole, LayoutChunks};
+pub use letters::LetterFormatter;
+pub use locale::Locale;
+pub use options::{FormatOptions, Formatted, Notation};
+pub use relative::RelativeFormatter;
+pub use scientific::{LoneDigit, ScientificFormatter, ScientificSettings};
+pub use smart::SmartFormatter;
+pub use suffix::SuffixFormatter;
+pub use words::WordFormatter;
+pub(crate) use words::{ENGLISH, ENGLISH_SHORT, GERMAN_SHORT, JAPANESE};
+
+/// Re-exports of every formatter and its options.
+///
+/// ```rust
+/// use approximint::fmt::prelude::*;
+/// use approximint::Approximint;
+///
+/// let options = FormatOptions::new().notation(Notation::Scientific);
+/// assert_eq!(options.format(Approximint::new(1_234)).to_string(), "1.234e3");
+/// let options = options.locale(Locale::de_de());
+/// assert_eq!(options.format(Approximint::new(1_234)).to_string(), "1,234E3");
+/// assert_eq!(SmartFormatter::new(Approximint::new(1_234_567)).to_string(), "1.23M");
+/// ```
+pub mod prelude {
+    pub use super::{
+        CanonicalFormatter, DecimalFormatter, DisplayBuffer, FormatOptions, Formatted,
+        LetterFormatter, Locale, LoneDigit, Notation, RelativeFormatter, Rounding,
+        ScientificFormatter, ScientificSettings, SmartFormatter, SuffixFormatter, WordFormatter,
+    };
+    #[cfg(feature = "std")]
+    pub use super::{ChunkRole, LayoutChunks};
+}
@@ -0,0 +1,467 @@
+use core::fmt::{Display, Write};
+
+use crate::fmt::common::write_padded;
+use crate::fmt::{
+    DecimalFormatter, Locale, LoneDigit, Rounding, ScientificFormatter, ScientificSettings,
+    WordFormatter,
+};
+use crate::Approximint;
+
+/// The notation used by [`FormatOptions`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum Notation {
+    /// Uses decimal notation for values that are stored exactly, and
+    /// scientific notation otherwise. This matches the [`Display`]
+    /// implementation of [`Approximint`].
+    #[default]
+    Automatic,
+    /// Formats using a [`DecimalFormatter`].
+    Decimal,
+    /// Formats using a [`ScientificFormatter`].
+    Scientific,
+    /// Formats using [`WordFormatter::english`].
+    English,
+}
+
+/// A reusable set of formatting options that can be applied to any
+/// [`Approximint`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct FormatOptions {
+    pub(crate) notation: Notation,
+    pub(crate) separator: char,
+    pub(crate) digits_per_separator: u8,
+    pub(crate) group_sizes: Option<&'static [u8]>,
+    pub(crate) decimal_places: u8,
+    pub(crate) plus_sign: bool,
+    pub(crate) negative_affixes: Option<(&'static str, &'static str)>,
+    pub(crate) scientific: ScientificSettings,
+}
+
+impl FormatOptions {
+    /// Returns the default formatting options.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            notation: Notation::Automatic,
+            separator: ',',
+            digits_per_separator: 3,
+            group_sizes: None,
+            decimal_places: 1,
+            plus_sign: false,
+            negative_affixes: None,
+            scientific: ScientificSettings::new(),
+        }
+    }
+
+    /// Returns the options used by version 1 of [`Approximint`]'s [`Display`]
+    /// implementation.
+    ///
+    /// Unlike [`new`](Self::new), these options will never change.
+    #[inline]
+    pub const fn v1() -> Self {
+        Self {
+            notation: Notation::Automatic,
+            separator: ',',
+            digits_per_separator: 3,
+            group_sizes: None,
+            decimal_places: 1,
+            plus_sign: false,
+            negative_affixes: None,
+            scientific: ScientificSettings {
+                decimal: '.',
+                significant_digits: 4,
+                keep_trailing_zeroes: true,
+                rounding: None,
+                plain_below: 0,
+                decimal_below: 0,
+                approx_marker: "",
+                lone_digit: LoneDigit::Bare,
+                exponent_marker: "e",
+                exponent_separator: None,
+                separator: ',',
+                group_sizes: None,
+            },
+        }
+    }
+
+    /// Sets the notation to format values with.
+    #[inline]
+    pub const fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Sets the character to use between grouped integer digits.
+    ///
+    /// The default separator is `,`.
+    #[inline]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of integer digits between each separator character.
+    ///
+    /// The default is 3.
+    #[inline]
+    pub const fn digits_per_separator(mut self, digits: u8) -> Self {
+        self.digits_per_separator = digits;
+        self.group_sizes = None;
+        self
+    }
+
+    /// Uses the symbols and digit grouping of `locale`.
+    #[inline]
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.separator = locale.separator;
+        self.group_sizes = Some(locale.group_sizes);
+        self.scientific = self.scientific.locale(locale);
+        self
+    }
+
+    /// Sets the character to use between the whole number and decimal digits
+    /// in scientific notation.
+    ///
+    /// By default, the decimal character is `.`.
+    #[inline]
+    pub const fn decimal(mut self, decimal: char) -> Self {
+        self.scientific = self.scientific.decimal(decimal);
+        self
+    }
+
+    /// Performs rounding on values displayed in scientific or word notation.
+    #[inline]
+    pub const fn rounded(mut self) -> Self {
+        self.scientific = self.scientific.rounded();
+        self
+    }
+
+    /// Rounds values displayed in scientific or word notation using
+    /// `rounding`.
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.scientific = self.scientific.rounding(rounding);
+        self
+    }
+
+    /// Sets the number of significant digits to display in scientific
+    /// notation.
+    ///
+    /// See [`ScientificSettings::significant_digits`] for the supported
+    /// range.
+    #[inline]
+    pub const fn significant_digits(mut self, digits: u8) -> Self {
+        self.scientific = self.scientific.significant_digits(digits);
+        self
+    }
+
+    /// Prevents displaying trailing zeroes in scientific notation.
+    #[inline]
+    pub const fn truncate_zeroes(mut self) -> Self {
+        self.scientific = self.scientific.truncate_zeroes();
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a word in
+    /// word notation.
+    ///
+    /// The default is 1.
+    #[inline]
+    pub const fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    /// Sets the number of digits displayed after the decimal point in both
+    /// scientific and word notation.
+    ///
+    /// This sets [`significant_digits`](Self::significant_digits) to
+    /// `digits + 1` and [`decimal_places`](Self::decimal_places) to `digits`.
+    /// The number of significant digits is limited to 9, or 8 when rounding.
+    #[inline]
+    pub const fn digits(self, digits: u8) -> Self {
+        let max = self.scientific.max_significant_digits();
+        let significant = digits.saturating_add(1);
+        self.significant_digits(if significant > max { max } else { significant })
+            .decimal_places(digits)
+    }
+
+    /// Displays a `+` before positive values.
+    #[inline]
+    pub const fn plus_sign(mut self) -> Self {
+        self.plus_sign = true;
+        self
+    }
+
+    /// Displays negative values by writing `prefix`, the value's magnitude,
+    /// and then `suffix`.
+    ///
+    /// By default, negative values are prefixed with `-`.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, FormatOptions};
+    ///
+    /// let options = FormatOptions::new().negative_affixes("", " CR");
+    /// assert_eq!(options.format(Approximint::new(-1_234)).to_string(), "1,234 CR");
+    /// assert_eq!(options.format(Approximint::new(1_234)).to_string(), "1,234");
+    /// ```
+    #[inline]
+    pub const fn negative_affixes(mut self, prefix: &'static str, suffix: &'static str) -> Self {
+        self.negative_affixes = Some((prefix, suffix));
+        self
+    }
+
+    /// Displays negative values in parentheses, such as `(1,234)`, as is
+    /// common in accounting.
+    ///
+    /// This is equivalent to `negative_affixes("(", ")")`.
+    #[inline]
+    pub const fn accounting(self) -> Self {
+        self.negative_affixes("(", ")")
+    }
+
+    /// Returns a [`Display`] implementor that formats `num` using these
+    /// options.
+    #[inline]
+    pub const fn format(&self, num: Approximint) -> Formatted {
+        Formatted {
+            num,
+            options: *self,
+        }
+    }
+
+    pub(crate) fn decimal_formatter(&self, num: Approximint) -> DecimalFormatter {
+        let formatter = DecimalFormatter::from(num)
+            .separator(self.separator)
+            .digits_per_separator(self.digits_per_separator);
+        match self.group_sizes {
+            Some(sizes) => formatter.group_sizes(sizes),
+            None => formatter,
+        }
+    }
+
+    fn scientific_formatter(&self, num: Approximint) -> ScientificFormatter {
+        ScientificFormatter::with_settings(num, self.scientific)
+    }
+}
+
+impl Default for FormatOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Display`] implementor that formats an [`Approximint`] using
+/// [`FormatOptions`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct Formatted {
+    num: Approximint,
+    options: FormatOptions,
+}
+
+impl Formatted {
+    /// Sets the notation to format the value with.
+    #[inline]
+    pub const fn notation(mut self, notation: Notation) -> Self {
+        self.options = self.options.notation(notation);
+        self
+    }
+
+    /// Formats the value using [`Notation::Decimal`].
+    #[inline]
+    pub const fn decimal_notation(self) -> Self {
+        self.notation(Notation::Decimal)
+    }
+
+    /// Formats the value using [`Notation::Scientific`].
+    #[inline]
+    pub const fn scientific(self) -> Self {
+        self.notation(Notation::Scientific)
+    }
+
+    /// Formats the value using [`Notation::English`].
+    #[inline]
+    pub const fn words(self) -> Self {
+        self.notation(Notation::English)
+    }
+
+    /// Sets the character to use between grouped integer digits.
+    ///
+    /// See [`FormatOptions::separator`].
+    #[inline]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.options = self.options.separator(separator);
+        self
+    }
+
+    /// Sets the number of integer digits between each separator character.
+    ///
+    /// See [`FormatOptions::digits_per_separator`].
+    #[inline]
+    pub const fn digits_per_separator(mut self, digits: u8) -> Self {
+        self.options = self.options.digits_per_separator(digits);
+        self
+    }
+
+    /// Uses the symbols and digit grouping of `locale`.
+    ///
+    /// See [`FormatOptions::locale`].
+    #[inline]
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.options = self.options.locale(locale);
+        self
+    }
+
+    /// Sets the character to use between the whole number and decimal digits
+    /// in scientific notation.
+    ///
+    /// See [`FormatOptions::decimal`].
+    #[inline]
+    pub const fn decimal(mut self, decimal: char) -> Self {
+        self.options = self.options.decimal(decimal);
+        self
+    }
+
+    /// Performs rounding on values displayed in scientific or word notation.
+    #[inline]
+    pub const fn rounded(mut self) -> Self {
+        self.options = self.options.rounded();
+        self
+    }
+
+    /// Rounds values displayed in scientific or word notation using
+    /// `rounding`.
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.options = self.options.rounding(rounding);
+        self
+    }
+
+    /// Sets the number of significant digits to display in scientific
+    /// notation.
+    ///
+    /// See [`FormatOptions::significant_digits`].
+    #[inline]
+    pub const fn significant_digits(mut self, digits: u8) -> Self {
+        self.options = self.options.significant_digits(digits);
+        self
+    }
+
+    /// Prevents displaying trailing zeroes in scientific notation.
+    #[inline]
+    pub const fn truncate_zeroes(mut self) -> Self {
+        self.options = self.options.truncate_zeroes();
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a word in
+    /// word notation.
+    ///
+    /// See [`FormatOptions::decimal_places`].
+    #[inline]
+    pub const fn decimal_places(mut self, places: u8) -> Self {
+        self.options = self.options.decimal_places(places);
+        self
+    }
+
+    /// Sets the number of digits displayed after the decimal point.
+    ///
+    /// See [`FormatOptions::digits`].
+    #[inline]
+    pub const fn digits(mut self, digits: u8) -> Self {
+        self.options = self.options.digits(digits);
+        self
+    }
+
+    /// Displays a `+` before positive values.
+    #[inline]
+    pub const fn plus_sign(mut self) -> Self {
+        self.options = self.options.plus_sign();
+        self
+    }
+
+    /// Displays negative values surrounded by `prefix` and `suffix`.
+    ///
+    /// See [`FormatOptions::negative_affixes`].
+    #[inline]
+    pub const fn negative_affixes(mut self, prefix: &'static str, suffix: &'static str) -> Self {
+        self.options = self.options.negative_affixes(prefix, suffix);
+        self
+    }
+
+    /// Displays negative values in parentheses, such as `(1,234)`.
+    ///
+    /// See [`FormatOptions::accounting`].
+    #[inline]
+    pub const fn accounting(mut self) -> Self {
+        self.options = self.options.accounting();
+        self
+    }
+
+    /// Returns the options used to format the value.
+    #[inline]
+    pub const fn options(&self) -> FormatOptions {
+        self.options
+    }
+}
+
+/// The precision of the format string, such as `{:.3}`, sets the number of
+/// significant digits in scientific notation and the maximum number of
+/// decimal places in word notation.
+impl Display for Formatted {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut this = *self;
+        if let Some(precision) = f.precision() {
+            this.options.scientific = this.options.scientific.with_precision(Some(precision));
+            this.options.decimal_places = u8::try_from(precision).unwrap_or(u8::MAX);
+        }
+        this.options.plus_sign |= f.sign_plus();
+        write_padded(f, |f| this.write_unpadded(f))
+    }
+}
+
+impl Formatted {
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let options = &self.options;
+        if options.plus_sign && self.num.is_positive() {
+            f.write_char('+')?;
+        }
+        if let (Some((prefix, suffix)), true) = (options.negative_affixes, self.num.is_negative()) {
+            f.write_str(prefix)?;
+            Self {
+                num: -self.num,
+                options: *options,
+            }
+            .write_unpadded(f)?;
+            return f.write_str(suffix);
+        }
+
+        match options.notation {
+            Notation::Automatic if self.num.ten_power > 0 => {
+                Display::fmt(&options.scientific_formatter(self.num), f)
+            }
+            Notation::Automatic | Notation::Decimal => {
+                Display::fmt(&options.decimal_formatter(self.num), f)
+            }
+            Notation::Scientific => Display::fmt(&options.scientific_formatter(self.num), f),
+            Notation::English => {
+                let mut formatter = WordFormatter::english(self.num)
+                    .separator(options.separator)
+                    .digits_per_separator(options.digits_per_separator)
+                    .decimal(options.scientific.decimal)
+                    .exponent_marker(options.scientific.exponent_marker)
+                    .decimal_places(options.decimal_places);
+                if let Some(sizes) = options.group_sizes {
+                    formatter = formatter.group_sizes(sizes);
+                }
+                if let Some(rounding) = options.scientific.rounding {
+                    formatter = formatter.rounding(rounding);
+                }
+                Display::fmt(&formatter, f)
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use core::fmt::{Display, Write};
+
+use crate::fmt::common::write_padded;
+use crate::{Approximint, Calculation};
+
+/// A [`Display`] implementor that formats the ratio between two
+/// [`Approximint`]s.
+///
+/// The ratio is displayed in one of three styles depending on its magnitude:
+///
+/// - Ratios of at least 1 are displayed as a multiplier, such as `2.3×`.
+/// - Ratios of at least 1% are displayed as a percentage, such as `45%`.
+/// - Smaller ratios are displayed as a fraction, such as `1/1,000th`.
+///
+/// The ratio is computed by comparing the values' exponents, allowing values
+/// of any magnitude to be compared.
+///
+/// ```rust
+/// use approximint::{Approximint, RelativeFormatter};
+///
+/// let income = Approximint::one_e(100);
+/// assert_eq!(
+///     RelativeFormatter::new(income * 23, income * 10).to_string(),
+///     "2.3×"
+/// );
+/// assert_eq!(
+///     RelativeFormatter::new(income * 45, income * 100).to_string(),
+///     "45%"
+/// );
+/// assert_eq!(
+///     RelativeFormatter::new(income, income * 1_000).to_string(),
+///     "1/1,000th"
+/// );
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct RelativeFormatter {
+    value: Approximint,
+    reference: Approximint,
+}
+
+impl RelativeFormatter {
+    /// Returns a formatter that displays `value` relative to `reference`.
+    #[inline]
+    pub const fn new(value: Approximint, reference: Approximint) -> Self {
+        Self { value, reference }
+    }
+
+    /// Returns `numerator / denominator` as a widened calculation with at
+    /// least 18 digits of precision.
+    fn quotient(numerator: Approximint, denominator: Approximint) -> Calculation {
+        Calculation::from_parts(
+            i128::from(numerator.coefficient.unsigned_abs()) * 10_i128.pow(18)
+                / i128::from(denominator.coefficient.unsigned_abs()),
+            i64::from(numerator.ten_power) - i64::from(denominator.ten_power) - 18,
+        )
+    }
+
+    fn write_tenths(tenths: Approximint, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let whole = tenths.coefficient / 10;
+        let fraction = tenths.coefficient % 10;
+        if fraction == 0 {
+            write!(f, "{whole}")
+        } else {
+            write!(f, "{whole}.{fraction}")
+        }
+    }
+
+    fn ordinal_suffix(value: Approximint) -> &'static str {
+        if value.ten_power > 0 || (11..=13).contains(&(value.coefficient % 100)) {
+            return "th";
+        }
+        match value.coefficient % 10 {
+            1 => "st",
+            2 => "nd",
+            3 => "rd",
+            _ => "th",
+        }
+    }
+}
+
+impl Display for RelativeFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_padded(f, |f| self.write_unpadded(f))
+    }
+}
+
+impl RelativeFormatter {
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.value.coefficient == 0 {
+            return f.write_str("0%");
+        }
+        if (self.value.coefficient < 0) != (self.reference.coefficient < 0) {
+            f.write_char('-')?;
+        }
+        if self.reference.coefficient == 0 {
+            return f.write_str("∞×");
+        }
+
+        let ratio = Self::quotient(self.value, self.reference);
+        let tenths = ratio.scaled(1).finish();
+        if tenths >= Approximint::new(10) {
+            if tenths < Approximint::new(10_000) {
+                Self::write_tenths(tenths, f)?;
+            } else {
+                Display::fmt(&ratio.finish(), f)?;
+            }
+            return f.write_char('×');
+        }
+
+        let percent_tenths = ratio.scaled(3).finish();
+        if percent_tenths >= Approximint::new(100) {
+            Display::fmt(&ratio.scaled(2).finish(), f)?;
+            f.write_char('%')
+        } else if percent_tenths >= Approximint::new(10) {
+            Self::write_tenths(percent_tenths, f)?;
+            f.write_char('%')
+        } else {
+            let reciprocal = Self::quotient(self.reference, self.value).finish();
+            write!(f, "1/{reciprocal}{}", Self::ordinal_suffix(reciprocal))
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use core::fmt::{Display, Write};
+
+use crate::fmt::common::{write_padded, Rounding, ScientificInfo};
+use crate::fmt::{DecimalFormatter, Locale};
+use crate::Approximint;
+
+/// A [`Display`] implementation that formats an [`Approximint`] using
+/// scientific notation.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[must_use]
+pub struct ScientificFormatter {
+    num: Approximint,
+    settings: ScientificSettings,
+    plus_sign: bool,
+}
+
+impl ScientificFormatter {
+    /// Returns a formatter for `num` using `settings`.
+    #[inline]
+    pub const fn with_settings(num: Approximint, settings: ScientificSettings) -> Self {
+        Self {
+            num,
+            settings,
+            plus_sign: false,
+        }
+    }
+
+    /// Sets the character to use between the whole number and decimal digits.
+    ///
+    /// By default, the decimal character is `.`.
+    #[inline]
+    pub const fn decimal(mut self, decimal: char) -> Self {
+        self.settings = self.settings.decimal(decimal);
+        self
+    }
+
+    /// Performs rounding on the displayed value.
+    ///
+    /// This is equivalent to `rounding(Rounding::HalfUp)`.
+    #[inline]
+    pub const fn rounded(mut self) -> Self {
+        self.settings = self.settings.rounded();
+        self
+    }
+
+    /// Rounds the displayed value using `rounding`.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, Rounding};
+    ///
+    /// let num = Approximint::new(12_345).as_scientific().significant_digits(4);
+    /// assert_eq!(num.rounding(Rounding::HalfUp).to_string(), "1.235e4");
+    /// assert_eq!(num.rounding(Rounding::HalfEven).to_string(), "1.234e4");
+    /// assert_eq!(num.rounding(Rounding::Ceil).to_string(), "1.235e4");
+    /// ```
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.settings = self.settings.rounding(rounding);
+        self
+    }
+
+    /// Sets the number of significant digits to display.
+    #[inline]
+    pub const fn significant_digits(mut self, digits: u8) -> Self {
+        self.settings = self.settings.significant_digits(digits);
+        self
+    }
+
+    /// Prevents displaying trailing zeroes.
+    #[inline]
+    pub const fn truncate_zeroes(mut self) -> Self {
+        self.settings = self.settings.truncate_zeroes();
+        self
+    }
+
+    /// Displays values whose exponent is less than `exponent` as plain
+    /// integers instead of using scientific notation.
+    ///
+    /// For example, `plain_below(3)` displays `512` as `512` rather than
+    /// `5.12e2`. By default, all non-zero values use scientific notation.
+    #[inline]
+    pub const fn plain_below(mut self, exponent: u32) -> Self {
+        self.settings = self.settings.plain_below(exponent);
+        self
+    }
+
+    /// Displays values whose exponent is less than `exponent` using decimal
+    /// notation with grouped digits instead of scientific notation.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let formatted = |num: Approximint| num.as_scientific().decimal_below(6).to_string();
+    /// assert_eq!(formatted(Approximint::new(999_999)), "999,999");
+    /// assert_eq!(formatted(Approximint::new(1_234_567)), "1.234e6");
+    /// ```
+    #[inline]
+    pub const fn decimal_below(mut self, exponent: u32) -> Self {
+        self.settings = self.settings.decimal_below(exponent);
+        self
+    }
+
+    /// Prefixes values that may be inexact with `marker`.
+    ///
+    /// See [`ScientificSettings::approx_marker`] for more information.
+    #[inline]
+    pub const fn approx_marker(mut self, marker: &'static str) -> Self {
+        self.settings = self.settings.approx_marker(marker);
+        self
+    }
+
+    /// Sets how a value is displayed when only its leading digit is shown.
+    ///
+    /// See [`LoneDigit`] for the available styles.
+    #[inline]
+    pub const fn lone_digit(mut self, style: LoneDigit) -> Self {
+        self.settings = self.settings.lone_digit(style);
+        self
+    }
+
+    /// Sets the text written between the digits and the exponent.
+    ///
+    /// See [`ScientificSettings::exponent_marker`] for more information.
+    #[inline]
+    pub const fn exponent_marker(mut self, marker: &'static str) -> Self {
+        self.settings = self.settings.exponent_marker(marker);
+        self
+    }
+
+    /// Writes `separator` between each group of three digits of the
+    /// exponent.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let huge = Approximint::new(9_999) * Approximint::one_e(u32::MAX);
+    /// assert_eq!(
+    ///     huge.as_scientific().exponent_separator(',').to_string(),
+    ///     "9.999e4,294,967,298"
+    /// );
+    /// ```
+    #[inline]
+    pub const fn exponent_separator(mut self, separator: char) -> Self {
+        self.settings = self.settings.exponent_separator(separator);
+        self
+    }
+
+    /// Uses the symbols and digit grouping of `locale`.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, Locale};
+    ///
+    /// let num = Approximint::new(1_234_567).as_scientific().locale(Locale::de_de());
+    /// assert_eq!(num.to_string(), "1,234E6");
+    /// assert_eq!(num.decimal_below(9).to_string(), "1.234.567");
+    /// ```
+    #[inline]
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.settings = self.settings.locale(locale);
+        self
+    }
+
+    /// Displays a `+` before positive values.
+    ///
+    /// This can also be enabled using the `+` flag of a format string, such
+    /// as `{:+}`.
+    #[inline]
+    pub const fn plus_sign(mut self) -> Self {
+        self.plus_sign = true;
+        self
+    }
+}
+
+impl From<Approximint> for ScientificFormatter {
+    #[inline]
+    fn from(num: Approximint) -> Self {
+        Self::with_settings(num, ScientificSettings::new())
+    }
+}
+
+/// The precision of the format string, such as `{:.3}`, sets the number of
+/// significant digits.
+impl Display for ScientificFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let this = Self {
+            num: self.num,
+            settings: self.settings.with_precision(f.precision()),
+            plus_sign: self.plus_sign || f.sign_plus(),
+        };
+        write_padded(f, |f| this.write_unpadded(f))
+    }
+}
+
+impl ScientificFormatter {
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.num.ten_power == 0 && self.num.coefficient == 0 {
+            return f.write_str("0");
+        }
+
+        if self.num.ten_power > 0 {
+            f.write_str(self.settings.approx_marker)?;
+        }
+        if self.plus_sign && self.num.is_positive() {
+            f.write_char('+')?;
+        }
+
+        let mut info = ScientificInfo::new(self.num);
+        if info.exponent < u64::from(self.settings.plain_below) {
+            return Display::fmt(
+                &DecimalFormatter::from(self.num)
+                    .digits_per_separator(0)
+                    .never_scientific(),
+                f,
+            );
+        } else if info.exponent < u64::from(self.settings.decimal_below) {
+            let mut decimal = DecimalFormatter::from(self.num)
+                .separator(self.settings.separator)
+                .never_scientific();
+            if let Some(sizes) = self.settings.group_sizes {
+                decimal = decimal.group_sizes(sizes);
+            }
+            return Display::fmt(&decimal, f);
+        }
+        if let Some(rounding) = self.settings.rounding {
+            info.round(usize::from(self.settings.significant_digits), rounding);
+        }
+        info.fmt(f, self.settings)
+    }
+}
+
+/// Settings for formatting values using scientific notation.
+///
+/// Settings can be created once and applied to many values using
+/// [`ScientificFormatter::with_settings`].
+///
+/// ```rust
+/// use approximint::{Approximint, ScientificFormatter, ScientificSettings};
+///
+/// const SETTINGS: ScientificSettings = ScientificSettings::new()
+///     .significant_digits(6)
+///     .decimal(',');
+///
+/// assert_eq!(
+///     ScientificFormatter::with_settings(Approximint::new(1_234_567_890), SETTINGS).to_string(),
+///     "1,23456e9"
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[must_use]
+pub struct ScientificSettings {
+    pub(crate) decimal: char,
+    pub(crate) significant_digits: u8,
+    pub(crate) keep_trailing_zeroes: bool,
+    pub(crate) rounding: Option<Rounding>,
+    pub(crate) plain_below: u32,
+    pub(crate) decimal_below: u32,
+    pub(crate) approx_marker: &'static str,
+    pub(crate) lone_digit: LoneDigit,
+    pub(crate) exponent_marker: &'static str,
+    pub(crate) exponent_separator: Option<char>,
+    pub(crate) separator: char,
+    pub(crate) group_sizes: Option<&'static [u8]>,
+}
+
+impl ScientificSettings {
+    /// Returns these settings with the number of significant digits set to
+    /// the precision of a format string, such as `{:.3}`, limited to the
+    /// supported range.
+    pub(crate) fn with_precision(self, precision: Option<usize>) -> Self {
+        let Some(precision) = precision else {
+            return self;
+        };
+        let max = self.max_significant_digits();
+        self.significant_digits(u8::try_from(precision).unwrap_or(max).clamp(1, max))
+    }
+
+    /// Returns the largest number of significant digits supported by these
+    /// settings.
+    pub(crate) const fn max_significant_digits(&self) -> u8 {
+        if self.rounding.is_some() {
+            8
+        } else {
+            9
+        }
+    }
+
+    /// Returns the default settings.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            decimal: '.',
+            significant_digits: 4,
+            keep_trailing_zeroes: true,
+            rounding: None,
+            plain_below: 0,
+            decimal_below: 0,
+            approx_marker: "",
+            lone_digit: LoneDigit::Bare,
+            exponent_marker: "e",
+            exponent_separator: None,
+            separator: ',',
+            group_sizes: None,
+        }
+    }
+
+    /// Sets the character to use between the whole number and decimal digits.
+    ///
+    /// By default, the decimal character is `.`.
+    #[inline]
+    pub const fn decimal(mut self, decimal: char) -> Self {
+        self.decimal = decimal;
+        self
+    }
+
+    /// Performs rounding on the displayed value.
+    ///
+    /// This is equivalent to `rounding(Rounding::HalfUp)`.
+    #[inline]
+    pub const fn rounded(self) -> Self {
+        self.rounding(Rounding::HalfUp)
+    }
+
+    /// Rounds the displayed value using `rounding`.
+    ///
+    /// By default, digits that are not displayed are truncated. Because
+    /// rounding supports at most 8 significant digits, a larger number of
+    /// significant digits is reduced to 8.
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        if self.significant_digits > 8 {
+            self.significant_digits = 8;
+        }
+        self
+    }
+
+    /// Sets the number of significant digits to display.
+    #[inline]
+    pub const fn significant_digits(mut self, digits: u8) -> Self {
+        if self.rounding.is_some() {
+            assert!(
+                digits <= 8,
+                "significant digits must be less than 9 when rounding"
+            );
+        } else {
+            assert!(
+                digits <= 9,
+                "significant digits must be less than or equal to 9"
+            );
+        }
+        self.significant_digits = digits;
+        self
+    }
+
+    /// Prevents displaying trailing zeroes.
+    #[inline]
+    pub const fn truncate_zeroes(mut self) -> Self {
+        self.keep_trailing_zeroes = false;
+        self
+    }
+
+    /// Displays values whose exponent is less than `exponent` as plain
+    /// integers instead of using scientific notation.
+    ///
+    /// By default, all non-zero values use scientific notation.
+    #[inline]
+    pub const fn plain_below(mut self, exponent: u32) -> Self {
+        self.plain_below = exponent;
+        self
+    }
+
+    /// Displays values whose exponent is less than `exponent` using decimal
+    /// notation with grouped digits instead of scientific notation.
+    ///
+    /// By default, all non-zero values use scientific notation.
+    #[inline]
+    pub const fn decimal_below(mut self, exponent: u32) -> Self {
+        self.decimal_below = exponent;
+        self
+    }
+
+    /// Prefixes values that may be inexact with `marker`, such as
+    /// `~1.234e56`.
+    ///
+    /// An [`Approximint`] only stores 9 significant digits. Values that have
+    /// more digits than can be stored may have had digits discarded, and
+    /// are displayed with this marker. Values that are stored exactly are
+    /// displayed without the marker.
+    ///
+    /// By default, no marker is displayed.
+    #[inline]
+    pub const fn approx_marker(mut self, marker: &'static str) -> Self {
+        self.approx_marker = marker;
+        self
+    }
+
+    /// Sets how a value is displayed when only its leading digit is shown.
+    ///
+    /// By default, [`LoneDigit::Bare`] is used.
+    #[inline]
+    pub const fn lone_digit(mut self, style: LoneDigit) -> Self {
+        self.lone_digit = style;
+        self
+    }
+
+    /// Sets the text written between the digits and the exponent, such as
+    /// `E` or ` × 10^`.
+    ///
+    /// By default, the exponent marker is `e`.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, ScientificFormatter, ScientificSettings};
+    ///
+    /// let settings = ScientificSettings::new().exponent_marker(" × 10^");
+    /// assert_eq!(
+    ///     ScientificFormatter::with_settings(Approximint::new(1_234_567), settings).to_string(),
+    ///     "1.234 × 10^6"
+    /// );
+    /// ```
+    #[inline]
+    pub const fn exponent_marker(mut self, marker: &'static str) -> Self {
+        self.exponent_marker = marker;
+        self
+    }
+
+    /// Writes `separator` between each group of three digits of the
+    /// exponent, such as `9.999e4,294,967,303`.
+    ///
+    /// By default, the exponent's digits are not grouped.
+    #[inline]
+    pub const fn exponent_separator(mut self, separator: char) -> Self {
+        self.exponent_separator = Some(separator);
+        self
+    }
+
+    /// Uses the symbols and digit grouping of `locale`.
+    ///
+    /// The digit grouping is used when displaying values below
+    /// [`decimal_below`](Self::decimal_below).
+    #[inline]
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.separator = locale.separator;
+        self.group_sizes = Some(locale.group_sizes);
+        self.decimal(locale.decimal)
+            .exponent_marker(locale.exponent_marker)
+    }
+}
+
+impl Default for ScientificSettings {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a number is displayed in scientific notation when only its leading
+/// digit is shown.
+///
+/// This occurs when [`ScientificSettings::truncate_zeroes`] removes every
+/// digit after the leading digit, or when only one significant digit is
+/// displayed.
+///
+/// ```rust
+/// use approximint::{Approximint, LoneDigit};
+///
+/// let million = Approximint::one_e(6).as_scientific().truncate_zeroes();
+/// assert_eq!(million.to_string(), "1e6");
+/// assert_eq!(million.lone_digit(LoneDigit::Point).to_string(), "1.e6");
+/// assert_eq!(million.lone_digit(LoneDigit::PointZero).to_string(), "1.0e6");
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum LoneDigit {
+    /// Displays the digit without a decimal point, such as `1e6`.
+    #[default]
+    Bare,
+    /// Displays the digit followed by a decimal point, such as `1.e6`.
+    Point,
+    /// Displays the digit followed by a decimal point and a zero, such as
+    /// `1.0e6`.
+    PointZero,
+}
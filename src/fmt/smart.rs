@@ -0,0 +1,163 @@
+use core::fmt::Display;
+
+use crate::fmt::{
+    DecimalFormatter, Rounding, ScientificFormatter, ScientificSettings, WordFormatter,
+    ENGLISH_SHORT,
+};
+use crate::Approximint;
+
+/// A [`Display`] implementor that chooses a notation based on the magnitude
+/// of an [`Approximint`].
+///
+/// Small values are formatted using decimal notation, values in the middle
+/// range are formatted using a word list, and large values are formatted
+/// using scientific notation.
+///
+/// ```rust
+/// use approximint::{Approximint, SmartFormatter};
+///
+/// assert_eq!(SmartFormatter::new(Approximint::new(999_999)).to_string(), "999,999");
+/// assert_eq!(SmartFormatter::new(Approximint::new(1_234_567)).to_string(), "1.23M");
+/// let huge = Approximint::new(12) * Approximint::one_e(40);
+/// assert_eq!(SmartFormatter::new(huge).to_string(), "1.2e41");
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct SmartFormatter {
+    num: Approximint,
+    words_at: u32,
+    scientific_at: u32,
+    words: &'static [(u32, &'static str)],
+    word_separator: &'static str,
+    decimal_places: u8,
+    rounding: Rounding,
+    scientific: ScientificSettings,
+}
+
+impl SmartFormatter {
+    /// Returns a formatter for `num` that uses decimal notation below one
+    /// million, short suffixes such as `1.23M` below `1e36`, and scientific
+    /// notation otherwise.
+    #[inline]
+    pub const fn new(num: Approximint) -> Self {
+        Self {
+            num,
+            words_at: 6,
+            scientific_at: 36,
+            words: &ENGLISH_SHORT,
+            word_separator: "",
+            decimal_places: 2,
+            rounding: Rounding::HalfDown,
+            scientific: ScientificSettings::new()
+                .significant_digits(3)
+                .truncate_zeroes(),
+        }
+    }
+
+    /// Formats values of at least `10^ten_power` using words.
+    ///
+    /// Smaller values are formatted using decimal notation. The default is 6.
+    #[inline]
+    pub const fn words_at(mut self, ten_power: u32) -> Self {
+        self.words_at = ten_power;
+        self
+    }
+
+    /// Formats values of at least `10^ten_power` using scientific notation.
+    ///
+    /// This takes priority over [`words_at`](Self::words_at). The default is
+    /// 36.
+    #[inline]
+    pub const fn scientific_at(mut self, ten_power: u32) -> Self {
+        self.scientific_at = ten_power;
+        self
+    }
+
+    /// Sets the words used for values in the middle range.
+    ///
+    /// `words` is a slice of pairs of powers of ten and the associated word,
+    /// in the same form as [`WordFormatter::new`]. The default is the list of
+    /// short suffixes used by [`WordFormatter::english_short`].
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, SmartFormatter};
+    ///
+    /// let formatter = SmartFormatter::new(Approximint::new(25) * Approximint::one_e(8))
+    ///     .words(&[(6, "million"), (9, "billion")])
+    ///     .word_separator(" ");
+    /// assert_eq!(formatter.to_string(), "2.5 billion");
+    /// ```
+    #[inline]
+    pub const fn words(mut self, words: &'static [(u32, &'static str)]) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Sets the text written between the value and each word.
+    ///
+    /// By default, no separator is written.
+    #[inline]
+    pub const fn word_separator(mut self, separator: &'static str) -> Self {
+        self.word_separator = separator;
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a word.
+    ///
+    /// Trailing zeroes are not displayed. The default is 2.
+    #[inline]
+    pub const fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    /// Rounds the digits displayed before a word using `rounding`.
+    ///
+    /// The default is [`Rounding::HalfDown`].
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the settings used for values formatted using scientific notation.
+    ///
+    /// By default, these values are displayed with 3 significant digits
+    /// without trailing zeroes, such as `1.2e41`.
+    #[inline]
+    pub const fn scientific(mut self, settings: ScientificSettings) -> Self {
+        self.scientific = settings;
+        self
+    }
+}
+
+impl From<Approximint> for SmartFormatter {
+    #[inline]
+    fn from(num: Approximint) -> Self {
+        Self::new(num)
+    }
+}
+
+impl Display for SmartFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let digits = self.num.integer_digits();
+        if digits > u64::from(self.scientific_at) {
+            Display::fmt(
+                &ScientificFormatter::with_settings(self.num, self.scientific),
+                f,
+            )
+        } else if digits > u64::from(self.words_at) && !self.words.is_empty() {
+            let smallest = self.words.iter().map(|(power, _)| *power).min();
+            Display::fmt(
+                &WordFormatter::new(self.num, self.words)
+                    .decimal_before_10_power(smallest.unwrap_or(0))
+                    .decimal_places(self.decimal_places)
+                    .word_separator(self.word_separator)
+                    .rounding(self.rounding),
+                f,
+            )
+        } else {
+            Display::fmt(&DecimalFormatter::from(self.num), f)
+        }
+    }
+}
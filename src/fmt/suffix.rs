@@ -0,0 +1,99 @@
+use core::fmt::Display;
+
+use crate::fmt::{Rounding, ScientificFormatter, ScientificSettings, WordFormatter, ENGLISH_SHORT};
+use crate::Approximint;
+
+/// A [`Display`] implementor that formats an [`Approximint`] using short
+/// suffixes, such as `1.23M` or `45.6Qa`.
+///
+/// This is the style most incremental games use. Values too large for the
+/// largest suffix are formatted using scientific notation instead.
+///
+/// ```rust
+/// use approximint::{Approximint, SuffixFormatter};
+///
+/// assert_eq!(SuffixFormatter::new(Approximint::new(999)).to_string(), "999");
+/// assert_eq!(SuffixFormatter::new(Approximint::new(1_234_567)).to_string(), "1.23M");
+/// let huge = Approximint::new(12) * Approximint::one_e(40);
+/// assert_eq!(SuffixFormatter::new(huge).to_string(), "1.2e41");
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct SuffixFormatter {
+    num: Approximint,
+    suffixes: &'static [(u32, &'static str)],
+    decimal_places: u8,
+    scientific: ScientificSettings,
+}
+
+impl SuffixFormatter {
+    /// Returns a formatter for `num` using the built-in suffixes: `K`, `M`,
+    /// `B`, `T`, `Qa`, `Qi`, `Sx`, `Sp`, `Oc`, `No`, and `Dc`.
+    #[inline]
+    pub fn new(num: Approximint) -> Self {
+        Self {
+            num,
+            suffixes: &ENGLISH_SHORT,
+            decimal_places: 2,
+            scientific: ScientificSettings::new()
+                .significant_digits(3)
+                .truncate_zeroes(),
+        }
+    }
+
+    /// Replaces the suffixes used by this formatter.
+    ///
+    /// `suffixes` is a slice of pairs of powers of ten and the associated
+    /// suffix, in the same form as [`WordFormatter::new`].
+    #[inline]
+    pub fn suffixes(mut self, suffixes: &'static [(u32, &'static str)]) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a suffix.
+    ///
+    /// Trailing zeroes are not displayed. The default is 2.
+    #[inline]
+    pub fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    /// Sets the settings used for values too large for the largest suffix.
+    ///
+    /// By default, these values are displayed with 3 significant digits
+    /// without trailing zeroes, such as `1.2e41`.
+    #[inline]
+    pub fn scientific(mut self, settings: ScientificSettings) -> Self {
+        self.scientific = settings;
+        self
+    }
+}
+
+impl Display for SuffixFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let largest = self
+            .suffixes
+            .iter()
+            .map(|(power, _)| u64::from(*power))
+            .max()
+            .unwrap_or(0);
+        if self.num.integer_digits() > largest + 3 {
+            Display::fmt(
+                &ScientificFormatter::with_settings(self.num, self.scientific),
+                f,
+            )
+        } else {
+            let smallest = self.suffixes.iter().map(|(power, _)| *power).min();
+            Display::fmt(
+                &WordFormatter::new(self.num, self.suffixes)
+                    .decimal_before_10_power(smallest.unwrap_or(0))
+                    .decimal_places(self.decimal_places)
+                    .word_separator("")
+                    .rounding(Rounding::HalfDown),
+                f,
+            )
+        }
+    }
+}
@@ -0,0 +1,755 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::fmt::{Display, Write};
+
+use crate::fmt::common::{write_padded, Rounding, ScientificInfo};
+use crate::fmt::{DecimalFormatter, Locale};
+use crate::Approximint;
+
+/// A [`Display`] implementation for an [`Approximint`] that uses a word list.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct WordFormatter<'a> {
+    decimal: DecimalFormatter,
+    decimal_before: u32,
+    words: WordList<'a>,
+    word_separator: &'a str,
+    decimal_mark: char,
+    exponent_marker: &'a str,
+    decimal_places: u8,
+    rounding: Option<Rounding>,
+    compact_residual: bool,
+    myriad: bool,
+}
+
+/// The words used by a [`WordFormatter`].
+#[derive(Clone, Debug)]
+enum WordList<'a> {
+    Borrowed(&'a [(u32, &'a str)]),
+    #[cfg(feature = "alloc")]
+    Owned(Arc<[(u32, String)]>),
+}
+
+impl WordList<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Borrowed(words) => words.len(),
+            #[cfg(feature = "alloc")]
+            Self::Owned(words) => words.len(),
+        }
+    }
+
+    fn get(&self, index: usize) -> (u32, &str) {
+        match self {
+            Self::Borrowed(words) => words[index],
+            #[cfg(feature = "alloc")]
+            Self::Owned(words) => (words[index].0, &words[index].1),
+        }
+    }
+}
+
+pub(crate) static ENGLISH: [(u32, &str); 33] = [
+    (3, "thousand"),
+    (6, "million"),
+    (9, "billion"),
+    (12, "trillion"),
+    (15, "quadrillion"),
+    (18, "quintillion"),
+    (21, "sextillion"),
+    (24, "septillion"),
+    (27, "octillion"),
+    (30, "nonillion"),
+    (33, "decillion"),
+    (36, "undecillion"),
+    (39, "duodecillion"),
+    (42, "tredecillion"),
+    (45, "quattuordecillion"),
+    (48, "quindecillion"),
+    (51, "sexdecillion"),
+    (54, "septendecillion"),
+    (57, "octodecillion"),
+    (60, "novemdecillion"),
+    (63, "vigintillion"),
+    (66, "unvigintillion"),
+    (69, "duovigintillion"),
+    (72, "trevigintillion"),
+    (75, "quattuorvigintillion"),
+    (78, "quinvigintillion"),
+    (81, "sexvigintillion"),
+    (84, "septenvigintillion"),
+    (87, "octovigintillion"),
+    (90, "novemvigintillion"),
+    (93, "trigintillion"),
+    (100, "googol"),
+    (303, "centillion"),
+];
+
+pub(crate) static ENGLISH_SHORT: [(u32, &str); 11] = [
+    (3, "K"),
+    (6, "M"),
+    (9, "B"),
+    (12, "T"),
+    (15, "Qa"),
+    (18, "Qi"),
+    (21, "Sx"),
+    (24, "Sp"),
+    (27, "Oc"),
+    (30, "No"),
+    (33, "Dc"),
+];
+
+pub(crate) static GERMAN_SHORT: [(u32, &str); 6] = [
+    (3, "Tsd."),
+    (6, "Mio."),
+    (9, "Mrd."),
+    (12, "Bio."),
+    (15, "Brd."),
+    (18, "Trio."),
+];
+
+pub(crate) static JAPANESE: [(u32, &str); 12] = [
+    (4, "万"),
+    (8, "億"),
+    (12, "兆"),
+    (16, "京"),
+    (20, "垓"),
+    (24, "秭"),
+    (28, "穣"),
+    (32, "溝"),
+    (36, "澗"),
+    (40, "正"),
+    (44, "載"),
+    (48, "極"),
+];
+
+static CHINESE: [(u32, &str); 12] = [
+    (4, "万"),
+    (8, "亿"),
+    (12, "兆"),
+    (16, "京"),
+    (20, "垓"),
+    (24, "秭"),
+    (28, "穰"),
+    (32, "沟"),
+    (36, "涧"),
+    (40, "正"),
+    (44, "载"),
+    (48, "极"),
+];
+
+static CHINESE_TRADITIONAL: [(u32, &str); 12] = [
+    (4, "萬"),
+    (8, "億"),
+    (12, "兆"),
+    (16, "京"),
+    (20, "垓"),
+    (24, "秭"),
+    (28, "穰"),
+    (32, "溝"),
+    (36, "澗"),
+    (40, "正"),
+    (44, "載"),
+    (48, "極"),
+];
+
+static KOREAN: [(u32, &str); 12] = [
+    (4, "만"),
+    (8, "억"),
+    (12, "조"),
+    (16, "경"),
+    (20, "해"),
+    (24, "자"),
+    (28, "양"),
+    (32, "구"),
+    (36, "간"),
+    (40, "정"),
+    (44, "재"),
+    (48, "극"),
+];
+
+impl WordFormatter<'static> {
+    /// Returns a formatter for the English language.
+    #[inline]
+    pub fn english(num: Approximint) -> Self {
+        Self::new(num, &ENGLISH).decimal_before_10_power(9)
+    }
+
+    /// Returns a formatter using short English suffixes, such as `1.23M`.
+    ///
+    /// Values of at least 1,000 are displayed with up to two decimal places
+    /// and rounded using [`Rounding::HalfDown`].
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// assert_eq!(WordFormatter::english_short(Approximint::new(999)).to_string(), "999");
+    /// assert_eq!(WordFormatter::english_short(Approximint::new(1_500)).to_string(), "1.5K");
+    /// assert_eq!(
+    ///     WordFormatter::english_short(Approximint::new(1_235_000)).to_string(),
+    ///     "1.23M"
+    /// );
+    /// ```
+    #[inline]
+    pub fn english_short(num: Approximint) -> Self {
+        Self::new(num, &ENGLISH_SHORT)
+            .decimal_before_10_power(3)
+            .decimal_places(2)
+            .word_separator("")
+            .rounding(Rounding::HalfDown)
+    }
+
+    /// Returns a formatter using German abbreviations, such as `12,5 Mrd.`.
+    ///
+    /// Values of at least 1 million are displayed with up to two decimal
+    /// places and rounded. Smaller values are displayed in full, using `.`
+    /// between groups of digits.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// assert_eq!(
+    ///     WordFormatter::german_short(Approximint::new(999_999)).to_string(),
+    ///     "999.999"
+    /// );
+    /// assert_eq!(
+    ///     WordFormatter::german_short(Approximint::new(12_345_678)).to_string(),
+    ///     "12,35 Mio."
+    /// );
+    /// ```
+    #[inline]
+    pub fn german_short(num: Approximint) -> Self {
+        Self::new(num, &GERMAN_SHORT)
+            .decimal_before_10_power(6)
+            .decimal_places(2)
+            .decimal(',')
+            .separator('.')
+            .rounded()
+    }
+
+    /// Returns a formatter using Japanese numerals based on powers of 10,000,
+    /// such as `1.5万`.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// assert_eq!(WordFormatter::japanese(Approximint::new(9_999)).to_string(), "9,999");
+    /// assert_eq!(WordFormatter::japanese(Approximint::new(15_000)).to_string(), "1.5万");
+    /// assert_eq!(
+    ///     WordFormatter::japanese(Approximint::new(123_456_789)).to_string(),
+    ///     "1.2億"
+    /// );
+    /// ```
+    #[inline]
+    pub fn japanese(num: Approximint) -> Self {
+        Self::new(num, &JAPANESE)
+            .decimal_before_10_power(4)
+            .word_separator("")
+    }
+
+    /// Returns a formatter using Simplified Chinese numerals based on powers
+    /// of 10,000, such as `1.5万`.
+    ///
+    /// Digits are grouped by four, such as `1,2345亿`.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// assert_eq!(WordFormatter::chinese(Approximint::new(9_999)).to_string(), "9999");
+    /// assert_eq!(WordFormatter::chinese(Approximint::new(15_000)).to_string(), "1.5万");
+    /// assert_eq!(
+    ///     WordFormatter::chinese(Approximint::new(123_456_789)).to_string(),
+    ///     "1.2亿"
+    /// );
+    /// ```
+    #[inline]
+    pub fn chinese(num: Approximint) -> Self {
+        Self::new(num, &CHINESE)
+            .decimal_before_10_power(4)
+            .word_separator("")
+            .myriad()
+    }
+
+    /// Returns a formatter using Traditional Chinese numerals based on powers
+    /// of 10,000, such as `1.5萬`.
+    ///
+    /// Digits are grouped by four, such as `1,2345億`.
+    #[inline]
+    pub fn chinese_traditional(num: Approximint) -> Self {
+        Self::new(num, &CHINESE_TRADITIONAL)
+            .decimal_before_10_power(4)
+            .word_separator("")
+            .myriad()
+    }
+
+    /// Returns a formatter using Korean numerals based on powers of 10,000,
+    /// such as `1.5만`.
+    ///
+    /// Digits are grouped by four, such as `1,2345억`.
+    #[inline]
+    pub fn korean(num: Approximint) -> Self {
+        Self::new(num, &KOREAN)
+            .decimal_before_10_power(4)
+            .word_separator("")
+            .myriad()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WordFormatter<'static> {
+    /// Returns a new formatter for `num` that owns its `words`.
+    ///
+    /// This is useful for words that are loaded at runtime, such as from a
+    /// localization file. `words` is in the same form as the word list of
+    /// [`new`](Self::new).
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// let words = vec![(3, String::from("mille")), (6, String::from("millions"))];
+    /// let formatter = WordFormatter::with_owned_words(Approximint::new(2_500_000), words);
+    /// assert_eq!(formatter.to_string(), "2.5 millions");
+    /// ```
+    #[inline]
+    pub fn with_owned_words(num: Approximint, words: Vec<(u32, String)>) -> Self {
+        Self {
+            words: WordList::Owned(Arc::from(words)),
+            ..Self::new(num, &[])
+        }
+    }
+}
+
+impl<'a> WordFormatter<'a> {
+    /// Returns a new formatter for `num` using the given `words`.
+    ///
+    /// `words` is a slice of pairs of powers of ten and the associated word.
+    /// For example, here is a portion of the English word list:
+    ///
+    /// ```rust
+    /// &[
+    ///     (3, "thousand"),
+    ///     (6, "million"),
+    ///     (9, "billion"),
+    ///     (12, "trillion"),
+    ///     // ...
+    /// ];
+    /// ```
+    ///
+    /// The formatter will reduce `num`'s ten-power by the largest matching
+    /// word, and repeat the process until the value is too small for any
+    /// eligible words. The remaining value will then be formatted using decimal
+    /// notation with a single decimal digit when the value is less than 1,000.
+    /// The number of decimal digits can be changed using
+    /// [`decimal_places`](Self::decimal_places).
+    ///
+    /// `words` may be borrowed from data loaded at runtime:
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// let loaded = String::from("mille,million");
+    /// let mut names = loaded.split(',');
+    /// let words = [(3, names.next().unwrap()), (6, names.next().unwrap())];
+    /// assert_eq!(
+    ///     WordFormatter::new(Approximint::new(2_500_000), &words).to_string(),
+    ///     "2.5 million"
+    /// );
+    /// ```
+    #[inline]
+    pub fn new(num: Approximint, words: &'a [(u32, &'a str)]) -> Self {
+        Self {
+            decimal: DecimalFormatter::from(num),
+            decimal_before: 0,
+            words: WordList::Borrowed(words),
+            word_separator: " ",
+            decimal_mark: '.',
+            exponent_marker: "e",
+            decimal_places: 1,
+            rounding: None,
+            compact_residual: false,
+            myriad: false,
+        }
+    }
+
+    /// Performs rounding before formatting the number.
+    ///
+    /// This is equivalent to `rounding(Rounding::HalfUp)`.
+    #[inline]
+    pub fn rounded(self) -> Self {
+        self.rounding(Rounding::HalfUp)
+    }
+
+    /// Rounds the displayed digits using `rounding` before formatting the
+    /// number.
+    ///
+    /// By default, digits that are not displayed are truncated.
+    #[inline]
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a word.
+    ///
+    /// Trailing zeroes are not displayed. The default is 1.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// let num = Approximint::new(123_456_789) * Approximint::one_e(3);
+    /// let formatted = |places: u8| WordFormatter::english(num).decimal_places(places).to_string();
+    /// assert_eq!(formatted(0), "123 billion");
+    /// assert_eq!(formatted(1), "123.4 billion");
+    /// assert_eq!(formatted(3), "123.456 billion");
+    /// ```
+    #[inline]
+    pub fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    /// Sets the maximum number of decimal digits displayed before a word,
+    /// limited to 8.
+    ///
+    /// An [`Approximint`] stores 9 significant digits, so at most 8 digits
+    /// can follow the leading digit. Larger values are treated as 8. See
+    /// [`decimal_places`](Self::decimal_places) for more information.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// let num = Approximint::new(123_456_789) * Approximint::one_e(3);
+    /// let formatted = |digits: u8| WordFormatter::english(num).fraction_digits(digits).to_string();
+    /// assert_eq!(formatted(0), "123 billion");
+    /// assert_eq!(formatted(8), "123.456789 billion");
+    /// assert_eq!(formatted(9), formatted(8));
+    /// ```
+    #[inline]
+    pub fn fraction_digits(self, digits: u8) -> Self {
+        self.decimal_places(digits.min(8))
+    }
+
+    /// Sets the character to use between the whole number and decimal digits.
+    ///
+    /// By default, the decimal character is `.`.
+    #[inline]
+    pub fn decimal(mut self, decimal: char) -> Self {
+        self.decimal_mark = decimal;
+        self
+    }
+
+    /// Sets the text written between the digits and the exponent when
+    /// [`compact_residual`](Self::compact_residual) displays a value using
+    /// scientific notation.
+    ///
+    /// By default, the exponent marker is `e`.
+    #[inline]
+    pub fn exponent_marker(mut self, marker: &'a str) -> Self {
+        self.exponent_marker = marker;
+        self
+    }
+
+    /// Sets the text written between the value and each word.
+    ///
+    /// The default separator is a single space.
+    #[inline]
+    pub fn word_separator(mut self, separator: &'a str) -> Self {
+        self.word_separator = separator;
+        self
+    }
+
+    /// Limits the number of digits displayed before a word.
+    ///
+    /// By default, the value before a word is displayed in full, such as
+    /// `999,999,999 googol`. When enabled, words smaller than
+    /// [`decimal_before_10_power`](Self::decimal_before_10_power) may be
+    /// used to shorten the value, producing `999.9 million googol`. If no word
+    /// is small enough, the value is displayed in scientific notation instead,
+    /// such as `9.999e8 googol`.
+    #[inline]
+    pub fn compact_residual(mut self) -> Self {
+        self.compact_residual = true;
+        self
+    }
+
+    /// Groups digits by powers of 10,000, as is common with the numerals of
+    /// China, Japan, and Korea.
+    ///
+    /// Integer digits are grouped by four, and at most four digits are
+    /// displayed before a word, such as `1,2345` and `9999万`. This is
+    /// intended for word lists whose powers are multiples of 4.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, WordFormatter};
+    ///
+    /// let num = Approximint::new(99_990_000);
+    /// assert_eq!(WordFormatter::japanese(num).to_string(), "9,999万");
+    /// assert_eq!(WordFormatter::japanese(num).myriad().to_string(), "9999万");
+    /// ```
+    #[inline]
+    pub fn myriad(mut self) -> Self {
+        self.myriad = true;
+        self.group_sizes(&[4])
+    }
+
+    /// Prevents using words for powers of ten less than or equal to
+    /// `ten_power`.
+    ///
+    /// The default English formatter sets this to 9, preventing values less
+    /// than 1 billion from being converted to words.
+    #[inline]
+    pub fn decimal_before_10_power(mut self, ten_power: u32) -> Self {
+        self.decimal_before = ten_power;
+        self
+    }
+
+    /// Sets the character to use between grouped integer digits.
+    ///
+    /// The default separator is `,`.
+    #[inline]
+    pub fn separator(mut self, separator: char) -> Self {
+        self.decimal.separator = separator;
+        self
+    }
+
+    /// Sets the number of integer digits between each separator character.
+    ///
+    /// The default is 3.
+    #[inline]
+    pub fn digits_per_separator(mut self, digits: u8) -> Self {
+        self.decimal = self.decimal.digits_per_separator(digits);
+        self
+    }
+
+    /// Sets the number of integer digits in each group, starting with the
+    /// group containing the ones digit.
+    ///
+    /// See [`DecimalFormatter::group_sizes`] for more information.
+    #[inline]
+    pub fn group_sizes(mut self, sizes: &'static [u8]) -> Self {
+        self.decimal = self.decimal.group_sizes(sizes);
+        self
+    }
+
+    /// Uses the symbols and digit grouping of `locale`.
+    #[inline]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.decimal = self.decimal.locale(locale);
+        self.decimal(locale.decimal)
+            .exponent_marker(locale.exponent_marker)
+    }
+
+    /// Displays a `+` before positive values, such as `+1.2 million`.
+    ///
+    /// This can also be enabled using the `+` flag of a format string, such
+    /// as `{:+}`.
+    #[inline]
+    pub fn plus_sign(mut self) -> Self {
+        self.decimal = self.decimal.plus_sign();
+        self
+    }
+
+    fn format_info(
+        &self,
+        info: ScientificInfo,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        if info.exponent < u64::from(self.decimal_before) {
+            return Display::fmt(&self.decimal, f);
+        } else if info.negative {
+            f.write_char('-')?;
+        } else if self.decimal.plus_sign {
+            f.write_char('+')?;
+        }
+
+        self.format_words(
+            info.exponent,
+            self.decimal_before,
+            self.significant_digits(),
+            f,
+            |f, exponent, significant_digits| {
+                if self.compact_residual && exponent >= u64::from(significant_digits) {
+                    return Self::format_compact(
+                        info,
+                        exponent,
+                        significant_digits,
+                        self.decimal_mark,
+                        self.exponent_marker,
+                        f,
+                    );
+                }
+                let exponent_usize =
+                    usize::try_from(exponent).expect("exponent too large for usize");
+                let grouping = self.decimal.grouping(exponent_usize);
+                let integer_digits = exponent_usize + 1;
+                let mut digits = info
+                    .digits
+                    .iter()
+                    .take(integer_digits + usize::from(self.decimal_places))
+                    .enumerate();
+                while let Some((index, digit)) = digits.next() {
+                    if index >= integer_digits {
+                        if digit == b'0' && digits.clone().all(|(_, digit)| digit == b'0') {
+                            break;
+                        } else if index == integer_digits {
+                            f.write_char(self.decimal_mark)?;
+                        }
+                    } else {
+                        grouping.write_before(index, f)?;
+                    }
+                    f.write_char(char::from(digit))?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn format_compact(
+        info: ScientificInfo,
+        exponent: u64,
+        significant_digits: u16,
+        decimal: char,
+        exponent_marker: &str,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let mut digits = info.digits.iter().take(usize::from(significant_digits));
+        if let Some(digit) = digits.next() {
+            f.write_char(char::from(digit))?;
+        }
+        let mut wrote_decimal = false;
+        while let Some(digit) = digits.next() {
+            if digit == b'0' && digits.clone().all(|digit| digit == b'0') {
+                break;
+            }
+            if !wrote_decimal {
+                f.write_char(decimal)?;
+                wrote_decimal = true;
+            }
+            f.write_char(char::from(digit))?;
+        }
+        write!(f, "{exponent_marker}{exponent}")
+    }
+
+    fn format_words(
+        &self,
+        exponent: u64,
+        minimum_power: u32,
+        significant_digits: u16,
+        f: &mut core::fmt::Formatter<'_>,
+        format_exponent: impl FnOnce(&mut core::fmt::Formatter<'_>, u64, u16) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let word = self.word_for(exponent, minimum_power);
+        let Some(exponent) = exponent.checked_sub(u64::from(word.0)) else {
+            return format_exponent(f, exponent, significant_digits);
+        };
+
+        if exponent < u64::from(significant_digits) {
+            format_exponent(f, exponent, significant_digits)?;
+        } else {
+            self.format_words(
+                exponent,
+                self.residual_minimum_power(),
+                significant_digits,
+                f,
+                format_exponent,
+            )?;
+        }
+
+        f.write_str(self.word_separator)?;
+        f.write_str(word.1)
+    }
+
+    /// Returns the number of digits displayed before a word.
+    fn significant_digits(&self) -> u16 {
+        if self.myriad {
+            4
+        } else {
+            u16::from(self.decimal.digits_per_separator) + 1
+        }
+    }
+
+    /// Returns the smallest word power used when the value before a word is
+    /// itself converted to words.
+    fn residual_minimum_power(&self) -> u32 {
+        if self.compact_residual {
+            0
+        } else {
+            self.decimal_before
+        }
+    }
+
+    /// Returns the largest word no smaller than `minimum_power` that fits
+    /// within `exponent`.
+    fn word_for(&self, exponent: u64, minimum_power: u32) -> (u32, &str) {
+        // info treats the leading digit as significant, but for the purpose of
+        // this function we need to treat exponent as a count of digits.
+        let last = self.words.len().checked_sub(1).expect("at least one word");
+        (0..last)
+            .map(|index| (self.words.get(index), self.words.get(index + 1).0))
+            .skip_while(|(word, _)| word.0 < minimum_power)
+            .find(|(word, next_power)| {
+                u64::from(word.0) <= exponent && u64::from(*next_power) > exponent
+            })
+            .map_or_else(|| self.words.get(last), |(word, _)| word)
+    }
+
+    /// Returns the number of significant digits that will be displayed for a
+    /// value whose leading digit is at `10^exponent`.
+    ///
+    /// This follows the same steps as [`format_words`](Self::format_words)
+    /// without writing any output.
+    fn displayed_digits(&self, mut exponent: u64) -> usize {
+        let significant_digits = self.significant_digits();
+        let mut minimum_power = self.decimal_before;
+        loop {
+            let word = self.word_for(exponent, minimum_power);
+            match exponent.checked_sub(u64::from(word.0)) {
+                Some(residual) if residual >= u64::from(significant_digits) => {
+                    exponent = residual;
+                    minimum_power = self.residual_minimum_power();
+                }
+                Some(residual) => {
+                    exponent = residual;
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        if self.compact_residual && exponent >= u64::from(significant_digits) {
+            usize::from(significant_digits)
+        } else {
+            usize::try_from(exponent)
+                .unwrap_or(usize::MAX)
+                .saturating_add(1 + usize::from(self.decimal_places))
+        }
+    }
+}
+
+/// The precision of the format string, such as `{:.3}`, sets the maximum
+/// number of decimal places.
+impl Display for WordFormatter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut this = self.clone();
+        if let Some(precision) = f.precision() {
+            this.decimal_places = u8::try_from(precision).unwrap_or(u8::MAX);
+        }
+        this.decimal.plus_sign |= f.sign_plus();
+        write_padded(f, |f| this.write_unpadded(f))
+    }
+}
+
+impl WordFormatter<'_> {
+    fn write_unpadded(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.decimal.num == Approximint::ZERO {
+            f.write_str("0")
+        } else {
+            let mut info = ScientificInfo::new(self.decimal.num);
+            if let Some(rounding) = self.rounding {
+                if info.exponent >= u64::from(self.decimal_before) {
+                    info.round(self.displayed_digits(info.exponent), rounding);
+                }
+            }
+            self.format_info(info, f)
+        }
+    }
+}
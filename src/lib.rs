@@ -1,12 +1,57 @@
 #![doc = include_str!(".crate-docs.md")]
 #![no_std]
-use core::fmt::{Debug, Display, Write};
-use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
-use core::slice;
-
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::ops::{
+    Add, AddAssign, Bound, Div, DivAssign, Mul, MulAssign, Neg, RangeBounds, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(any(feature = "std", test))]
 extern crate std;
 
+/// Panics with the formatted message if `$lossy` is true, the `strict-debug`
+/// feature is enabled, and debug assertions are enabled.
+macro_rules! strict_debug_assert {
+    ($lossy:expr, $($message:tt)+) => {
+        if cfg!(all(feature = "strict-debug", debug_assertions)) && $lossy {
+            panic!($($message)+);
+        }
+    };
+}
+
+#[cfg(feature = "bench")]
+pub mod bench;
+mod calc;
+mod cmp;
+mod convert;
+mod fixed;
+#[cfg(any(not(feature = "std"), test))]
+mod float;
+pub mod fmt;
+mod parse;
+#[cfg(feature = "plot")]
+pub mod plot;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "vector")]
+pub mod vector;
+
+pub use calc::Calculation;
+pub use convert::{TryFromApproximintError, TryFromFloatError};
+pub use fixed::{Approxfixed, FormattedFixed};
+pub use fmt::{
+    CanonicalFormatter, DecimalFormatter, DisplayBuffer, FormatOptions, Formatted, LetterFormatter,
+    Locale, LoneDigit, Notation, RelativeFormatter, Rounding, ScientificFormatter,
+    ScientificSettings, SmartFormatter, SuffixFormatter, WordFormatter,
+};
+#[cfg(feature = "std")]
+pub use fmt::{ChunkRole, LayoutChunks};
+use parse::Marks;
+pub use parse::{FromStrError, ParseError, ParseErrorKind};
+
 /// An integer type that approximates its value using storage inspired by
 /// scientific notation.
 ///
@@ -15,7 +60,27 @@ extern crate std;
 /// and the maximum exponent is `u32::MAX`. This approach supports a range of
 /// `-9.999_999_99e4_294_967_303..=9.999_999_99e4_294_967_303` while retaining 9
 /// digits of precision.
-#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default)]
+///
+/// Zero has a single representation: every operation that produces zero
+/// returns a value equal to [`Approximint::ZERO`], regardless of the
+/// magnitude of the operands involved.
+///
+/// # Output stability
+///
+/// The [`Display`] and [`Debug`] output of this type is part of its public
+/// API, and changing it requires a new major version:
+///
+/// - Values stored exactly are displayed in decimal notation with `,`
+///   between groups of 3 digits, such as `-1,234,567`.
+/// - All other values are displayed in scientific notation with 4
+///   significant digits, truncated rather than rounded, such as `1.234e42`.
+///   [`Debug`] instead displays up to 9 significant digits and omits
+///   trailing zeroes, such as `1.23456789e42`.
+///
+/// If the default output must change in the future, the current format will
+/// remain available through [`display_v1`](Self::display_v1) and
+/// [`debug_v1`](Self::debug_v1).
+#[derive(Clone, Copy, Eq, PartialEq, Default, Hash)]
 pub struct Approximint {
     ten_power: u32,
     coefficient: i32,
@@ -72,6 +137,25 @@ impl Approximint {
         .normalize_underflow()
     }
 
+    /// Returns this value multiplied by `10^exponent`, saturating if the
+    /// result is out of range.
+    const fn with_added_ten_power(self, exponent: u32) -> Self {
+        if let Some(ten_power) = self.ten_power.checked_add(exponent) {
+            Self {
+                ten_power,
+                coefficient: self.coefficient,
+            }
+            .normalize_underflow()
+        } else if self.coefficient == 0 {
+            Self::ZERO
+        } else {
+            Self {
+                coefficient: self.coefficient.signum() * 999_999_999,
+                ten_power: u32::MAX,
+            }
+        }
+    }
+
     const fn normalized(self) -> Self {
         self.normalize_underflow().normalize_overflow()
     }
@@ -87,6 +171,8 @@ impl Approximint {
                 self.coefficient *= 10;
                 self.ten_power -= 1;
             }
+        } else {
+            self.ten_power = 0;
         }
 
         self
@@ -106,6 +192,18 @@ impl Approximint {
         self
     }
 
+    const fn checked_normalized(self) -> Option<Self> {
+        let normalized = self.normalize_underflow();
+        if normalized.ten_power == u32::MAX
+            && (normalized.coefficient >= Self::COEFFICIENT_LIMIT
+                || normalized.coefficient <= -Self::COEFFICIENT_LIMIT)
+        {
+            None
+        } else {
+            Some(normalized.normalize_overflow())
+        }
+    }
+
     const fn match_powers(left: Self, right: Self) -> (Self, Self) {
         let left = left.normalized();
         let right = right.normalized();
@@ -118,6 +216,38 @@ impl Approximint {
         }
     }
 
+    /// Returns `value` truncated to the nearest representable value, and
+    /// whether any non-zero digits were truncated.
+    #[expect(clippy::cast_possible_truncation)]
+    const fn approximate_i128(mut value: i128) -> (Self, bool) {
+        let mut lossy = false;
+        let mut ten_power = 0;
+        while value >= Self::COEFFICIENT_LIMIT as i128 || value <= -Self::COEFFICIENT_LIMIT as i128
+        {
+            ten_power += 1;
+            lossy |= value % 10 != 0;
+            value /= 10;
+        }
+
+        (
+            Self {
+                coefficient: value as i32,
+                ten_power,
+            },
+            lossy,
+        )
+    }
+
+    /// Returns true if adding or subtracting `self` and `other` ignores one
+    /// of the non-zero operands entirely.
+    const fn discards_operand(self, other: Self) -> bool {
+        if self.coefficient == 0 || other.coefficient == 0 {
+            return false;
+        }
+        let (lhs, rhs) = Self::match_powers(self, other);
+        lhs.coefficient == 0 || rhs.coefficient == 0
+    }
+
     const fn adjusted_powers(mut lower: Self, higher: Self) -> (Self, Self) {
         while lower.ten_power < higher.ten_power {
             lower.coefficient /= 10;
@@ -130,843 +260,2267 @@ impl Approximint {
         (lower, higher)
     }
 
-    /// Returns a [`Display`] implementor that formats this number using English
-    /// words.
-    pub fn as_english(self) -> WordFormatter<'static> {
-        WordFormatter::english(self)
+    /// Returns `self + rhs`, saturating if the result is out of range.
+    ///
+    /// This function produces the same result as the [`Add`] implementation,
+    /// but can be used in const contexts.
+    #[must_use]
+    #[inline]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        let (lhs, rhs) = Self::match_powers(self, rhs);
+        Self {
+            // Adding two numbers less than 1 billion will never overflow u32
+            coefficient: lhs.coefficient + rhs.coefficient,
+            ten_power: lhs.ten_power,
+        }
+        .normalized()
     }
 
-    /// Returns a [`Display`] implementor that formats this number using
-    /// scientific notation.
-    pub fn as_scientific(self) -> ScientificFormatter {
-        ScientificFormatter::from(self)
+    /// Returns `self - rhs`, saturating if the result is out of range.
+    ///
+    /// This function produces the same result as the [`Sub`] implementation,
+    /// but can be used in const contexts.
+    #[must_use]
+    #[inline]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        let (lhs, rhs) = Self::match_powers(self, rhs);
+        Self {
+            coefficient: lhs.coefficient - rhs.coefficient,
+            ten_power: lhs.ten_power,
+        }
+        .normalized()
     }
 
-    /// Returns a [`Display`] implementor that formats this number using decimal
-    /// notation.
-    pub fn as_decimal(self) -> DecimalFormatter {
-        DecimalFormatter::from(self)
+    /// Returns `self * rhs`, saturating if the result is out of range.
+    ///
+    /// This function produces the same result as the [`Mul`] implementation,
+    /// but can be used in const contexts.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// const START: Approximint = Approximint::new(5).const_mul(Approximint::one_e(6));
+    /// assert_eq!(START, Approximint::new(5_000_000));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn const_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(result) => result,
+            None if (self.coefficient < 0) == (rhs.coefficient < 0) => Self::MAX,
+            None => Self::MIN,
+        }
     }
 
-    /// Returns the result of raising `self` to the `exponent` power.
+    /// Returns `self * numerator / denominator`, rounded half away from zero
+    /// to the nearest representable value.
     ///
-    /// Rather than aiming for accuracy, this function only attempts two
-    /// approaches to avoiding overflows when raising to large powers. If an
-    /// overflow occurs during raising the coefficient to the exponent power,
-    /// the entire calcualtion will overflow rather than an invalid result being
-    /// returned.
+    /// This function only uses integer arithmetic, which makes it a
+    /// deterministic alternative to multiplying by an `f64`: the result is
+    /// identical on every platform. Results that are too large to be
+    /// represented saturate.
     ///
-    /// Due to these limitations, this function should be only used to raise to
-    /// powers that overflows are unlikely to occur.
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let gold = Approximint::new(1_000);
+    /// assert_eq!(gold.mul_ratio(3, 2), Approximint::new(1_500));
+    /// assert_eq!(gold.mul_ratio(2, 3), Approximint::new(667));
+    /// assert_eq!(gold.mul_ratio(1, 3_000), Approximint::ZERO);
+    ///
+    /// let large = Approximint::new(1) * Approximint::one_e(12);
+    /// assert_eq!(
+    ///     large.mul_ratio(1, 3),
+    ///     Approximint::new(333_333_333) * Approximint::one_e(3)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `denominator` is zero.
     #[must_use]
     #[expect(clippy::cast_possible_truncation)]
-    pub fn powi(self, exponent: u32) -> Self {
-        if self.coefficient == 0 {
-            Self::ZERO
-        } else if exponent > 0 {
-            let this = self.maximize_ten_power();
-            let ten_power = this.ten_power.checked_pow(exponent);
-            let coefficient = i128::from(this.coefficient)
-                .checked_pow(exponent)
-                .or_else(|| {
-                    let raised = f64::from(this.coefficient)
-                        .powi(i32::try_from(exponent).unwrap_or(i32::MAX))
-                        as i128;
-                    (raised > i128::MIN && raised < i128::MAX).then_some(raised)
-                });
-            let (Some(coefficient), Some(ten_power)) = (coefficient, ten_power) else {
-                return Self {
-                    coefficient: this.coefficient.signum() * 999_999_999,
-                    ten_power: u32::MAX,
-                };
+    pub const fn mul_ratio(self, numerator: u64, denominator: u64) -> Self {
+        assert!(denominator != 0, "attempt to divide by zero");
+        if self.coefficient == 0 || numerator == 0 {
+            return Self::ZERO;
+        }
+
+        let limit = Self::COEFFICIENT_LIMIT as u128;
+        let divisor = denominator as u128;
+        let product = self.coefficient.unsigned_abs() as u128 * numerator as u128;
+        let mut quotient = product / divisor;
+        let mut remainder = product % divisor;
+        let mut ten_power = self.ten_power as u64;
+        // Move digits out of the ten power until the quotient has enough
+        // significant digits.
+        while quotient < limit && ten_power > 0 {
+            remainder *= 10;
+            quotient = quotient * 10 + remainder / divisor;
+            remainder %= divisor;
+            ten_power -= 1;
+        }
+
+        if quotient < limit {
+            if remainder * 2 >= divisor {
+                quotient += 1;
+            }
+        } else {
+            // Any digits beyond the remainder can't change the rounding of
+            // the digits being discarded.
+            let mut scale = 1;
+            while quotient / scale >= limit {
+                scale *= 10;
+                ten_power += 1;
+            }
+            quotient = (quotient + scale / 2) / scale;
+        }
+        if quotient >= limit {
+            quotient /= 10;
+            ten_power += 1;
+        }
+
+        if ten_power > u32::MAX as u64 {
+            return if self.coefficient < 0 {
+                Self::MIN
+            } else {
+                Self::MAX
             };
-            let mut normalized = coefficient.approximate();
-            normalized.ten_power = normalized.ten_power.saturating_add(ten_power);
-            normalized.normalize_underflow()
+        }
+        let coefficient = if self.coefficient < 0 {
+            -(quotient as i32)
         } else {
-            Self::ONE
+            quotient as i32
+        };
+        Self {
+            coefficient,
+            ten_power: ten_power as u32,
         }
+        .normalized()
     }
 
-    const fn maximize_ten_power(mut self) -> Self {
-        if self.coefficient != 0 {
-            while self.coefficient % 10 == 0 {
-                self.ten_power += 1;
-                self.coefficient /= 10;
-            }
-        }
-        self
+    /// Returns `self * rhs`, rounded half away from zero to the nearest
+    /// representable value.
+    ///
+    /// [`Mul<i32>`](Mul) allows multiplying by integer literals directly,
+    /// such as `cost * 5`. This function supports unsigned integers that may
+    /// not fit in an `i32`.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let cost = Approximint::new(120);
+    /// assert_eq!(cost.mul_u32(5), Approximint::new(600));
+    /// assert_eq!(
+    ///     cost.mul_u32(4_000_000_000),
+    ///     Approximint::new(48) * Approximint::one_e(10)
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn mul_u32(self, rhs: u32) -> Self {
+        self.mul_ratio(rhs as u64, 1)
     }
-}
 
-impl Neg for Approximint {
-    type Output = Self;
+    /// Returns `self * rhs`, rounded half away from zero to the nearest
+    /// representable value.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let cost = Approximint::new(120);
+    /// assert_eq!(
+    ///     cost.mul_u64(10_000_000_000_000_000_000),
+    ///     Approximint::new(12) * Approximint::one_e(20)
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn mul_u64(self, rhs: u64) -> Self {
+        self.mul_ratio(rhs, 1)
+    }
+
+    /// Returns `self / rhs`, truncating any fractional portion and saturating
+    /// if the result is out of range.
+    ///
+    /// This function produces the same result as the [`Div`] implementation,
+    /// but can be used in const contexts. Combined with
+    /// [`const_mul`](Self::const_mul), this allows percentage-based growth to
+    /// be computed at compile time.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// // A base cost of 1,000 with 15% growth.
+    /// const NEXT_COST: Approximint = Approximint::new(1_000)
+    ///     .const_mul(Approximint::new(115))
+    ///     .const_div(Approximint::new(100));
+    /// assert_eq!(NEXT_COST, Approximint::new(1_150));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use]
+    #[inline]
+    pub const fn const_div(self, rhs: Self) -> Self {
+        assert!(rhs.coefficient != 0, "attempt to divide by zero");
+        match self.checked_div(rhs) {
+            Some(result) => result,
+            None if (self.coefficient < 0) == (rhs.coefficient < 0) => Self::MAX,
+            None => Self::MIN,
+        }
+    }
 
+    /// Returns `-self`.
+    ///
+    /// This function produces the same result as the [`Neg`] implementation,
+    /// but can be used in const contexts.
+    #[must_use]
     #[inline]
-    fn neg(self) -> Self::Output {
+    pub const fn const_neg(self) -> Self {
         Self {
             ten_power: self.ten_power,
             coefficient: -self.coefficient,
         }
     }
-}
 
-impl Add for Approximint {
-    type Output = Self;
+    /// Returns the value halfway between `self` and `other`, rounded to the
+    /// nearest representable value.
+    ///
+    /// Unlike `(self + other) / 2`, the sum of the values never saturates, so
+    /// this function returns the correct result even for values near
+    /// [`Approximint::MAX`].
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::MAX.midpoint(Approximint::MAX), Approximint::MAX);
+    /// assert_eq!(Approximint::new(3).midpoint(Approximint::new(8)), Approximint::new(6));
+    /// ```
+    #[must_use]
+    pub fn midpoint(self, other: Self) -> Self {
+        self.calc().add(other).mul(Self::new(5)).scaled(-1).finish()
+    }
+
+    /// Returns the mean of `values`, or `None` if `values` is empty.
+    ///
+    /// The sum of the values never saturates, so this function returns the
+    /// correct result even for values near [`Approximint::MAX`].
+    #[must_use]
+    pub fn average(values: &[Self]) -> Option<Self> {
+        let (first, rest) = values.split_first()?;
+        let sum = rest.iter().fold(first.calc(), |sum, value| sum.add(*value));
+        Some(sum.divided(values.len() as u64).finish())
+    }
 
+    /// Returns the absolute value of `self`.
+    #[must_use]
     #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) = Self::match_powers(self, rhs);
+    pub const fn abs(self) -> Self {
         Self {
-            // Adding two numbers less than 1 billion will never overflow u32
-            coefficient: lhs.coefficient + rhs.coefficient,
-            ten_power: lhs.ten_power,
+            ten_power: self.ten_power,
+            coefficient: self.coefficient.abs(),
         }
-        .normalized()
     }
-}
-impl Add<i32> for Approximint {
-    type Output = Self;
 
+    /// Returns the absolute difference between `self` and `other`,
+    /// saturating if the result is out of range.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let balance = Approximint::new(250);
+    /// let price = Approximint::new(1_000);
+    /// assert_eq!(balance.abs_diff(price), Approximint::new(750));
+    /// assert_eq!(price.abs_diff(balance), Approximint::new(750));
+    /// ```
+    #[must_use]
     #[inline]
-    fn add(self, rhs: i32) -> Self::Output {
-        self + Self::new(rhs)
+    pub const fn abs_diff(self, other: Self) -> Self {
+        self.const_sub(other).abs()
     }
-}
 
-impl AddAssign for Approximint {
+    /// Returns a number representing the sign of `self`.
+    ///
+    /// - `0` if the number is zero
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    #[must_use]
     #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+    pub const fn signum(self) -> Self {
+        Self::new(self.coefficient.signum())
     }
-}
 
-impl AddAssign<i32> for Approximint {
+    /// Returns a value with the magnitude of `self` and the sign of
+    /// `sign`.
+    ///
+    /// Because zero has no sign, a `sign` of zero is treated as positive.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let value = Approximint::new(42);
+    /// assert_eq!(value.copysign(Approximint::new(-1)), Approximint::new(-42));
+    /// assert_eq!((-value).copysign(Approximint::ZERO), value);
+    /// ```
+    #[must_use]
     #[inline]
-    fn add_assign(&mut self, rhs: i32) {
-        *self += Self::new(rhs);
+    pub const fn copysign(self, sign: Self) -> Self {
+        if sign.is_negative() {
+            self.abs().const_neg()
+        } else {
+            self.abs()
+        }
     }
-}
-
-impl Sub for Approximint {
-    type Output = Self;
 
+    /// Returns true if `self` is zero.
+    #[must_use]
     #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) = Self::match_powers(self, rhs);
-        Self {
-            coefficient: lhs.coefficient - rhs.coefficient,
-            ten_power: lhs.ten_power,
-        }
-        .normalized()
+    pub const fn is_zero(self) -> bool {
+        self.coefficient == 0
     }
-}
-
-impl Sub<i32> for Approximint {
-    type Output = Self;
 
+    /// Returns true if `self` is greater than zero.
+    #[must_use]
     #[inline]
-    fn sub(self, rhs: i32) -> Self::Output {
-        self - Self::new(rhs)
+    pub const fn is_positive(self) -> bool {
+        self.coefficient > 0
     }
-}
 
-impl SubAssign for Approximint {
+    /// Returns true if `self` is less than zero.
+    #[must_use]
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+    pub const fn is_negative(self) -> bool {
+        self.coefficient < 0
     }
-}
 
-impl SubAssign<i32> for Approximint {
+    /// Returns `self + rhs`, or `None` if the result is out of range.
+    #[must_use]
     #[inline]
-    fn sub_assign(&mut self, rhs: i32) {
-        *self -= Self::new(rhs);
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = Self::match_powers(self, rhs);
+        Self {
+            coefficient: lhs.coefficient + rhs.coefficient,
+            ten_power: lhs.ten_power,
+        }
+        .checked_normalized()
     }
-}
 
-impl Mul for Approximint {
-    type Output = Self;
+    /// Returns `self - rhs`, or `None` if the result is out of range.
+    #[must_use]
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = Self::match_powers(self, rhs);
+        Self {
+            coefficient: lhs.coefficient - rhs.coefficient,
+            ten_power: lhs.ten_power,
+        }
+        .checked_normalized()
+    }
+
+    /// Returns `self * rhs`, or `None` if the result is out of range.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        if self.coefficient == 0 || rhs.coefficient == 0 {
+            return Some(Self::ZERO);
+        }
+        let lhs = self.normalize_underflow();
+        let rhs = rhs.normalize_underflow();
+        let mut coefficient = lhs.coefficient as i64 * rhs.coefficient as i64;
+        let Some(mut ten_power) = lhs.ten_power.checked_add(rhs.ten_power) else {
+            return None;
+        };
+        while coefficient >= Self::COEFFICIENT_LIMIT as i64
+            || coefficient <= -Self::COEFFICIENT_LIMIT as i64
+        {
+            let Some(next_power) = ten_power.checked_add(1) else {
+                return None;
+            };
+            ten_power = next_power;
+            coefficient /= 10;
+        }
+        Some(Self {
+            coefficient: coefficient as i32,
+            ten_power,
+        })
+    }
+
+    /// Returns `self / rhs`, truncating any fractional portion. Returns `None`
+    /// if `rhs` is zero or the result is out of range.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.coefficient == 0 {
+            return None;
+        } else if self.coefficient == 0 {
+            return Some(Self::ZERO);
+        }
+
+        let (mut coefficient, mut ten_power) = self.wide_quotient(rhs);
+        while coefficient >= Self::COEFFICIENT_LIMIT as i128
+            || coefficient <= -Self::COEFFICIENT_LIMIT as i128
+        {
+            coefficient /= 10;
+            ten_power += 1;
+        }
+
+        if ten_power < 0 {
+            coefficient = if ten_power >= -38 {
+                coefficient / 10_i128.pow(-ten_power as u32)
+            } else {
+                0
+            };
+            ten_power = 0;
+        } else if ten_power > u32::MAX as i64 {
+            return None;
+        }
+
+        Some(
+            Self {
+                coefficient: coefficient as i32,
+                ten_power: ten_power as u32,
+            }
+            .normalized(),
+        )
+    }
+
+    /// Returns `self / rhs` as a coefficient and power of ten without any
+    /// truncation.
+    const fn wide_quotient(self, rhs: Self) -> (i128, i64) {
+        // Widening the numerator by 18 digits ensures the quotient always has
+        // at least 9 digits of precision.
+        (
+            self.coefficient as i128 * 10_i128.pow(18) / rhs.coefficient as i128,
+            self.ten_power as i64 - rhs.ten_power as i64 - 18,
+        )
+    }
+
+    /// Returns `self / rhs` as a floating point number.
+    ///
+    /// The quotient is computed from the values' coefficients and exponents,
+    /// so the result is accurate even when both values are far outside of the
+    /// range of `f64`. Quotients too large for an `f64` saturate to
+    /// [`f64::MAX`] or [`f64::MIN`] rather than becoming infinite.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let cost = Approximint::one_e(1_000) * 4;
+    /// let current = Approximint::one_e(1_000);
+    /// assert!((current.ratio(cost) - 0.25).abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn ratio(self, rhs: Self) -> f64 {
+        assert!(rhs.coefficient != 0, "attempt to divide by zero");
+        let (coefficient, ten_power) = self.wide_quotient(rhs);
+        let saturated = if coefficient < 0 { f64::MIN } else { f64::MAX };
+        let coefficient = coefficient as f64;
+        // The coefficient has at most 27 digits, so exponents outside of this
+        // range always overflow or underflow.
+        if ten_power > 400 {
+            saturated
+        } else if ten_power < -400 {
+            0.
+        } else {
+            // Scaling in two steps prevents the power of ten from overflowing
+            // or underflowing when the result doesn't.
+            let ten_power = i32::try_from(ten_power).expect("checked above");
+            let half = ten_power / 2;
+            let quotient = coefficient * ten_powi(half) * ten_powi(ten_power - half);
+            if quotient.is_infinite() {
+                saturated
+            } else {
+                quotient
+            }
+        }
+    }
+
+    /// Returns the whole quotient of `self / rhs` and the fractional portion
+    /// of the quotient.
+    ///
+    /// The fraction is in the range `(-1.0, 1.0)` and has the same sign as the
+    /// quotient. When the quotient has more digits than can be stored, the
+    /// fraction is always 0.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let (upgrades, next) = Approximint::new(1_234_560).ratio_parts(Approximint::new(1_000));
+    /// assert_eq!(upgrades, Approximint::new(1_234));
+    /// assert!((next - 0.56).abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn ratio_parts(self, rhs: Self) -> (Self, f64) {
+        let quotient = self / rhs;
+        if self.coefficient == 0 || quotient.ten_power > 0 {
+            return (quotient, 0.);
+        }
+
+        let (coefficient, ten_power) = self.wide_quotient(rhs);
+        let fraction = match u32::try_from(-ten_power) {
+            Ok(places) if places <= 38 => {
+                let divisor = 10_i128.pow(places);
+                (coefficient % divisor) as f64 / divisor as f64
+            }
+            // The quotient is too small to be represented by an f64.
+            _ => 0.,
+        };
+        (quotient, fraction)
+    }
+
+    /// Returns `self + rhs`.
+    ///
+    /// Unlike the [`Add`] implementation, this function panics instead of
+    /// saturating when the result is out of range. This can be used to catch
+    /// unintended overflows during development.
+    #[must_use]
+    #[inline]
+    pub const fn strict_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(result) => result,
+            None => panic!("attempt to add with overflow"),
+        }
+    }
+
+    /// Returns `self - rhs`.
+    ///
+    /// Unlike the [`Sub`] implementation, this function panics instead of
+    /// saturating when the result is out of range.
+    #[must_use]
+    #[inline]
+    pub const fn strict_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(result) => result,
+            None => panic!("attempt to subtract with overflow"),
+        }
+    }
+
+    /// Returns `self * rhs`.
+    ///
+    /// Unlike the [`Mul`] implementation, this function panics instead of
+    /// saturating when the result is out of range.
+    #[must_use]
+    #[inline]
+    pub const fn strict_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(result) => result,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Returns `self / rhs`.
+    ///
+    /// Unlike the [`Div`] implementation, this function panics instead of
+    /// saturating when the result is out of range.
+    #[must_use]
+    #[inline]
+    pub fn strict_div(self, rhs: Self) -> Self {
+        assert!(rhs.coefficient != 0, "attempt to divide by zero");
+        self.checked_div(rhs)
+            .expect("attempt to divide with overflow")
+    }
+
+    /// Returns a [`Calculation`] starting with this value.
+    ///
+    /// Calculations evaluate chains of operations using a widened
+    /// intermediate value, only rounding once the result is finished.
+    #[inline]
+    pub fn calc(self) -> Calculation {
+        Calculation::new(self)
+    }
+
+    /// Returns `self * rhs`, rounding the product to 9 significant digits
+    /// using `rounding`.
+    ///
+    /// The product is computed using `f64` arithmetic, and `rounding` is
+    /// applied to that product. This allows guaranteeing that a result is
+    /// never larger than the floating point product, such as when crediting
+    /// currency:
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, Rounding};
+    ///
+    /// let earned = Approximint::new(999);
+    /// assert_eq!(earned * 0.5, Approximint::new(500));
+    /// assert_eq!(earned.mul_f64_with(0.5, Rounding::Floor), Approximint::new(499));
+    /// assert_eq!(earned.mul_f64_with(-0.5, Rounding::Floor), Approximint::new(-500));
+    /// assert_eq!(
+    ///     earned.mul_f64_with(-0.5, Rounding::TowardZero),
+    ///     Approximint::new(-499)
+    /// );
+    /// ```
+    ///
+    /// The [`Mul<f64>`](Mul) implementation is equivalent to using
+    /// [`Rounding::HalfUp`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn mul_f64_with(self, rhs: f64, rounding: Rounding) -> Self {
+        let coefficient = f64::from(self.coefficient) * rhs;
+        if coefficient.is_nan() {
+            return Self::ZERO;
+        } else if coefficient.is_infinite() {
+            return if rhs.is_finite() {
+                // The product only overflowed the f64.
+                self * Self::approximate(rhs)
+            } else if coefficient < 0. {
+                Self::MIN
+            } else {
+                Self::MAX
+            };
+        }
+
+        let decimals = coefficient.abs().log10();
+        let mut places_to_shift = (9.0 - decimals).floor() as i32;
+        let ten_power = if let Some(ten_power) = self.ten_power.checked_add_signed(-places_to_shift)
+        {
+            ten_power
+        } else if places_to_shift < 0 {
+            return if coefficient < 0. {
+                Self::MIN
+            } else {
+                Self::MAX
+            };
+        } else {
+            places_to_shift = self.ten_power as i32;
+            0
+        };
+
+        // Dividing by an exact power of ten avoids the rounding error of
+        // multiplying by its inexact reciprocal.
+        let shifted = if places_to_shift < 0 {
+            coefficient / 10f64.powi(-places_to_shift)
+        } else {
+            coefficient * 10f64.powi(places_to_shift)
+        };
+        let rounded = match rounding {
+            Rounding::HalfUp => shifted.round(),
+            Rounding::HalfDown => (shifted.abs() - 0.5).ceil().copysign(shifted),
+            Rounding::HalfEven => shifted.round_ties_even(),
+            Rounding::Floor => shifted.floor(),
+            Rounding::Ceil => shifted.ceil(),
+            Rounding::TowardZero => shifted.trunc(),
+        };
+        Self {
+            coefficient: rounded as i32,
+            ten_power,
+        }
+        .normalized()
+    }
+
+    /// Returns the closest `f64` to this value.
+    ///
+    /// Only values with 15 or fewer significant digits and magnitudes up to
+    /// `10^22` are guaranteed to convert exactly. Values beyond the range of
+    /// `f64` convert to positive or negative infinity.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::new(-1_500).to_f64_lossy(), -1_500.);
+    /// let value = Approximint::new(15) * Approximint::one_e(11);
+    /// assert_eq!(value.to_f64_lossy(), 1.5e12);
+    /// assert_eq!(Approximint::MAX.to_f64_lossy(), f64::INFINITY);
+    /// assert_eq!(Approximint::MIN.to_f64_lossy(), f64::NEG_INFINITY);
+    /// ```
+    #[must_use]
+    pub fn to_f64_lossy(self) -> f64 {
+        let coefficient = f64::from(self.coefficient);
+        if self.ten_power == 0 {
+            return coefficient;
+        }
+        coefficient * ten_powi(i32::try_from(self.ten_power).unwrap_or(i32::MAX))
+    }
+
+    /// Returns the memory representation of this value as a byte array in
+    /// little-endian byte order.
+    ///
+    /// The layout is stable: the first 4 bytes are the coefficient as an
+    /// `i32`, followed by 4 bytes of the power of ten as a `u32`. Both are
+    /// stored in little-endian byte order.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let value = Approximint::new(-5) * Approximint::one_e(100);
+    /// let bytes = value.to_le_bytes();
+    /// assert_eq!(Approximint::from_le_bytes(bytes), value);
+    /// assert_eq!(Approximint::new(1).to_le_bytes(), [1, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        let coefficient = self.coefficient.to_le_bytes();
+        let ten_power = self.ten_power.to_le_bytes();
+        [
+            coefficient[0],
+            coefficient[1],
+            coefficient[2],
+            coefficient[3],
+            ten_power[0],
+            ten_power[1],
+            ten_power[2],
+            ten_power[3],
+        ]
+    }
+
+    /// Returns the memory representation of this value as a byte array in
+    /// big-endian byte order.
+    ///
+    /// The layout matches [`to_le_bytes`](Self::to_le_bytes), except that the
+    /// coefficient and power of ten are each stored in big-endian byte order.
+    #[must_use]
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        let coefficient = self.coefficient.to_be_bytes();
+        let ten_power = self.ten_power.to_be_bytes();
+        [
+            coefficient[0],
+            coefficient[1],
+            coefficient[2],
+            coefficient[3],
+            ten_power[0],
+            ten_power[1],
+            ten_power[2],
+            ten_power[3],
+        ]
+    }
+
+    /// Returns a value from its representation as a byte array in
+    /// little-endian byte order.
+    ///
+    /// See [`to_le_bytes`](Self::to_le_bytes) for the layout. Coefficients
+    /// outside of the supported range are normalized, saturating if needed.
+    #[must_use]
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self {
+            coefficient: i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            ten_power: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        }
+        .normalized()
+    }
+
+    /// Returns a value from its representation as a byte array in big-endian
+    /// byte order.
+    ///
+    /// See [`to_be_bytes`](Self::to_be_bytes) for the layout. Coefficients
+    /// outside of the supported range are normalized, saturating if needed.
+    #[must_use]
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self {
+            coefficient: i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            ten_power: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        }
+        .normalized()
+    }
+
+    /// Returns a [`Display`] implementor that formats this number using English
+    /// words.
+    pub fn as_english(self) -> WordFormatter<'static> {
+        WordFormatter::english(self)
+    }
+
+    /// Returns a [`Display`] implementor that formats this number using
+    /// scientific notation.
+    pub fn as_scientific(self) -> ScientificFormatter {
+        ScientificFormatter::from(self)
+    }
+
+    /// Returns a [`Display`] implementor that formats this number using decimal
+    /// notation.
+    pub fn as_decimal(self) -> DecimalFormatter {
+        DecimalFormatter::from(self)
+    }
+
+    /// Returns a [`Display`] implementor that formats this number using short
+    /// suffixes, such as `1.23M`.
+    pub fn as_suffixed(self) -> SuffixFormatter {
+        SuffixFormatter::new(self)
+    }
+
+    /// Returns a [`Display`] implementor that formats this number's exact
+    /// coefficient and exponent.
+    ///
+    /// The result can be parsed without loss using
+    /// [`from_canonical_str`](Self::from_canonical_str).
+    pub fn as_canonical(self) -> CanonicalFormatter {
+        CanonicalFormatter::from(self)
+    }
+
+    /// Parses a value formatted by [`as_canonical`](Self::as_canonical).
+    ///
+    /// The input must be an optionally negative coefficient of at most 9
+    /// digits followed by `e` and a non-negative exponent that fits in a
+    /// `u32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't in the canonical format or if
+    /// either component is out of range.
+    pub fn from_canonical_str(input: &str) -> Result<Self, ParseError<'_>> {
+        parse::canonical(input)
+    }
+
+    /// Returns a [`Display`] implementor whose notation and options can be
+    /// configured by chaining calls.
+    ///
+    /// By default, the result is formatted the same as this type's [`Display`]
+    /// implementation.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let n = Approximint::new(123_456_789) * 1_000;
+    /// assert_eq!(n.display().words().digits(2).rounded().to_string(), "123.46 billion");
+    /// assert_eq!(n.display().scientific().digits(2).to_string(), "1.23e11");
+    /// assert_eq!(n.display().plus_sign().to_string(), "+1.234e11");
+    /// ```
+    #[inline]
+    pub const fn display(self) -> Formatted {
+        FormatOptions::new().format(self)
+    }
+
+    /// Returns an iterator over the runs of text produced by formatting this
+    /// value using `options`, along with the role of each run.
+    ///
+    /// This allows rich text user interfaces to style each part of a number
+    /// differently.
+    ///
+    /// ```rust
+    /// use approximint::{Approximint, ChunkRole, FormatOptions, Notation};
+    ///
+    /// let options = FormatOptions::new().notation(Notation::Scientific);
+    /// let chunks: Vec<_> = (-Approximint::one_e(42)).layout_chunks(&options).collect();
+    /// assert_eq!(
+    ///     chunks,
+    ///     [
+    ///         (String::from("-"), ChunkRole::Sign),
+    ///         (String::from("1"), ChunkRole::Digits),
+    ///         (String::from("."), ChunkRole::Decimal),
+    ///         (String::from("000"), ChunkRole::Digits),
+    ///         (String::from("e42"), ChunkRole::Exponent),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn layout_chunks(self, options: &FormatOptions) -> LayoutChunks {
+        LayoutChunks::new(self, options)
+    }
+
+    /// Returns a [`Display`] implementor that formats this value using
+    /// version 1 of this type's [`Display`] output.
+    ///
+    /// The output of this function will never change, even if the
+    /// [`Display`] implementation changes in a future major version.
+    #[inline]
+    pub const fn display_v1(self) -> Formatted {
+        FormatOptions::v1().format(self)
+    }
+
+    /// Returns a [`Display`] implementor that formats this value using
+    /// version 1 of this type's [`Debug`] output.
+    ///
+    /// The output of this function will never change, even if the [`Debug`]
+    /// implementation changes in a future major version.
+    #[inline]
+    pub const fn debug_v1(self) -> Formatted {
+        FormatOptions::v1()
+            .significant_digits(9)
+            .truncate_zeroes()
+            .format(self)
+    }
+
+    /// Parses a value formatted by a [`WordFormatter`] using `words`.
+    ///
+    /// The input is a number in decimal or scientific notation followed by
+    /// any number of words from `words`, separated by optional whitespace.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// const WORDS: &[(u32, &str)] = &[(3, "k"), (100, "googol")];
+    /// assert_eq!(
+    ///     Approximint::parse_words("1.5k googol", WORDS),
+    ///     Ok(Approximint::new(1_500) * Approximint::one_e(100))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is invalid or if the input contains a
+    /// word that isn't in `words`.
+    pub fn parse_words<'a>(input: &'a str, words: &[(u32, &str)]) -> Result<Self, ParseError<'a>> {
+        parse::words(input, words, Marks::DEFAULT)
+    }
+
+    /// Parses a number followed by an optional suffix from `suffixes`.
+    ///
+    /// This is intended for parsing user input using short suffixes, such as
+    /// `1.2K`. Suffixes are matched ignoring ASCII case, whitespace is
+    /// allowed between the number and the suffix, and surrounding whitespace
+    /// is ignored.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// const SUFFIXES: &[(u32, &str)] = &[(3, "k"), (6, "m")];
+    /// assert_eq!(
+    ///     Approximint::parse_suffixed(" 1.5 M ", SUFFIXES),
+    ///     Ok(Approximint::new(1_500_000))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is invalid or if the suffix isn't in
+    /// `suffixes`.
+    pub fn parse_suffixed<'a>(
+        input: &'a str,
+        suffixes: &[(u32, &str)],
+    ) -> Result<Self, ParseError<'a>> {
+        parse::suffixed(input, suffixes)
+    }
+
+    /// Parses a number followed by an optional short English suffix, such as
+    /// `1.2K` or `3.4Qa`.
+    ///
+    /// The suffixes are the same as [`WordFormatter::english_short`]: `K`,
+    /// `M`, `B`, `T`, `Qa`, `Qi`, `Sx`, `Sp`, `Oc`, `No`, and `Dc`. See
+    /// [`parse_suffixed`](Self::parse_suffixed) for the rules used.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::parse_short("1.2k"), Ok(Approximint::new(1_200)));
+    /// assert_eq!(
+    ///     Approximint::parse_short("3.4Qa"),
+    ///     Ok(Approximint::new(34) * Approximint::one_e(14))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is invalid or if the suffix isn't a
+    /// short English suffix.
+    pub fn parse_short(input: &str) -> Result<Self, ParseError<'_>> {
+        parse::suffixed(input, &fmt::ENGLISH_SHORT)
+    }
+
+    /// Parses a value formatted by [`WordFormatter::english`] or
+    /// [`WordFormatter::english_short`].
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let value = Approximint::parse_english("123.4 million").unwrap();
+    /// assert_eq!(value, Approximint::new(123_400_000));
+    /// assert_eq!(Approximint::parse_english("1.23M"), Ok(Approximint::new(1_230_000)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is invalid or if the input contains a
+    /// word that isn't an English number word.
+    pub fn parse_english(input: &str) -> Result<Self, ParseError<'_>> {
+        parse::words(input, &fmt::ENGLISH, Marks::DEFAULT).or_else(|err| {
+            parse::words(input, &fmt::ENGLISH_SHORT, Marks::DEFAULT).map_err(|_| err)
+        })
+    }
+
+    /// Parses a value formatted by [`WordFormatter::german_short`].
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(
+    ///     Approximint::parse_german_short("12,5 Mrd."),
+    ///     Ok(Approximint::new(125) * Approximint::one_e(8))
+    /// );
+    /// assert_eq!(Approximint::parse_german_short("999.999"), Ok(Approximint::new(999_999)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is invalid or if the input contains a
+    /// word that isn't a German abbreviation.
+    pub fn parse_german_short(input: &str) -> Result<Self, ParseError<'_>> {
+        parse::words(input, &fmt::GERMAN_SHORT, Marks::GERMAN)
+    }
+
+    /// Parses a value formatted by [`WordFormatter::japanese`].
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::parse_japanese("1.5万"), Ok(Approximint::new(15_000)));
+    /// assert_eq!(
+    ///     Approximint::parse_japanese("3億"),
+    ///     Ok(Approximint::new(300_000_000))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is invalid or if the input contains a
+    /// word that isn't a Japanese numeral.
+    pub fn parse_japanese(input: &str) -> Result<Self, ParseError<'_>> {
+        parse::words(input, &fmt::JAPANESE, Marks::DEFAULT)
+    }
+
+    /// Writes this value as a JSON value to `writer`.
+    ///
+    /// Values that fit in an `i64` are written as JSON numbers without any
+    /// separators, such as `1234567890000`. All other values are written as a
+    /// JSON string containing all significant digits in scientific notation,
+    /// such as `"1.23456789e100"`. This ensures that JSON parsers that read
+    /// numbers into 64-bit integers or floats never lose precision.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let mut json = String::new();
+    /// Approximint::new(-42).write_json_number(&mut json).unwrap();
+    /// json.push(',');
+    /// (Approximint::one_e(100) * 123).write_json_number(&mut json).unwrap();
+    /// assert_eq!(json, r#"-42,"1.23e102""#);
+    /// ```
+    pub fn write_json_number(self, writer: &mut impl core::fmt::Write) -> core::fmt::Result {
+        // The largest 18 digit number is less than i64::MAX.
+        if self.integer_digits() <= 18 {
+            write!(writer, "{}", self.as_decimal().digits_per_separator(0))
+        } else {
+            write!(
+                writer,
+                "\"{}\"",
+                self.as_scientific().significant_digits(9).truncate_zeroes()
+            )
+        }
+    }
+
+    /// Returns the result of raising `self` to the `exponent` power.
+    ///
+    /// Rather than aiming for accuracy, this function only attempts two
+    /// approaches to avoiding overflows when raising to large powers. If an
+    /// overflow occurs during raising the coefficient to the exponent power,
+    /// the entire calcualtion will overflow rather than an invalid result being
+    /// returned.
+    ///
+    /// Due to these limitations, this function should be only used to raise to
+    /// powers that overflows are unlikely to occur.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn powi(self, exponent: u32) -> Self {
+        if self.coefficient == 0 {
+            Self::ZERO
+        } else if exponent > 0 {
+            let this = self.maximize_ten_power();
+            let ten_power = this.ten_power.checked_pow(exponent);
+            let coefficient = i128::from(this.coefficient)
+                .checked_pow(exponent)
+                .or_else(|| {
+                    let exponent = i32::try_from(exponent).unwrap_or(i32::MAX);
+                    #[cfg(feature = "std")]
+                    let raised = f64::from(this.coefficient).powi(exponent) as i128;
+                    #[cfg(not(feature = "std"))]
+                    let raised = float::powi(f64::from(this.coefficient), exponent) as i128;
+                    (raised > i128::MIN && raised < i128::MAX).then_some(raised)
+                });
+            let (Some(coefficient), Some(ten_power)) = (coefficient, ten_power) else {
+                return Self {
+                    coefficient: this.coefficient.signum() * 999_999_999,
+                    ten_power: u32::MAX,
+                };
+            };
+            let (mut normalized, _) = Self::approximate_i128(coefficient);
+            normalized.ten_power = normalized.ten_power.saturating_add(ten_power);
+            normalized.normalize_underflow()
+        } else {
+            Self::ONE
+        }
+    }
+
+    /// Returns the square root of this value, rounded to the nearest
+    /// representable value.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::new(144).sqrt(), Approximint::new(12));
+    /// assert_eq!(Approximint::one_e(1_000_000).sqrt(), Approximint::one_e(500_000));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is negative.
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        self.nth_root(2)
+    }
+
+    /// Returns the cube root of this value, rounded to the nearest
+    /// representable value.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::new(-27).cbrt(), Approximint::new(-3));
+    /// ```
+    #[must_use]
+    pub fn cbrt(self) -> Self {
+        self.nth_root(3)
+    }
+
+    /// Returns the `n`th root of this value, rounded to the nearest
+    /// representable value.
+    ///
+    /// The root is calculated without converting to a floating point number,
+    /// allowing roots of any value to be computed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n` is zero, or if `n` is even and `self` is
+    /// negative.
+    #[must_use]
+    pub fn nth_root(self, n: u32) -> Self {
+        assert!(n > 0, "zeroth root is undefined");
+        assert!(
+            n % 2 == 1 || self.coefficient >= 0,
+            "even root of a negative number"
+        );
+        if n == 1 || self.coefficient == 0 {
+            return self;
+        }
+
+        let root = Calculation::new(self).root(n);
+        if self.coefficient < 0 {
+            -root
+        } else {
+            root
+        }
+    }
+
+    /// Returns the geometric midpoint of `self` and `other`, which is the
+    /// square root of their product.
+    ///
+    /// For values that grow exponentially, such as the costs of upgrade tiers,
+    /// this is the value halfway between `self` and `other`. The product is
+    /// computed without saturating, so the result is correct for values of
+    /// any magnitude.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let low = Approximint::new(100);
+    /// let high = Approximint::one_e(10);
+    /// assert_eq!(low.midpoint_geometric(high), Approximint::one_e(6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` and `other` have different signs.
+    #[must_use]
+    pub fn midpoint_geometric(self, other: Self) -> Self {
+        if self.coefficient == 0 || other.coefficient == 0 {
+            return Self::ZERO;
+        }
+        assert!(
+            self.is_negative() == other.is_negative(),
+            "geometric midpoint of values with different signs"
+        );
+
+        let root = Calculation::new(self).mul(other).root(2);
+        if self.is_negative() {
+            -root
+        } else {
+            root
+        }
+    }
+
+    /// Returns the base 10 logarithm of this value.
+    ///
+    /// The logarithm of the coefficient is combined with the ten power, so
+    /// this function is accurate for values far outside of the range of
+    /// `f64`. Zero returns negative infinity, and negative values return NaN.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::one_e(1_000_000).log10(), 1_000_000.);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn log10(self) -> f64 {
+        f64::from(self.coefficient).log10() + f64::from(self.ten_power)
+    }
+
+    /// Returns `10^exponent`, rounded to the nearest representable value.
+    ///
+    /// This is the inverse of [`log10`](Self::log10). Exponents too large to
+    /// be represented saturate to [`Approximint::MAX`], and NaN returns zero.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::ten_powf(2.5), Approximint::new(316));
+    /// let huge = Approximint::one_e(1_000_000) * 3;
+    /// assert_eq!(Approximint::ten_powf(huge.log10()), huge);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn ten_powf(exponent: f64) -> Self {
+        if exponent.is_nan() || exponent < -1. {
+            return Self::ZERO;
+        } else if exponent >= f64::from(u32::MAX) + 9. {
+            return Self::MAX;
+        }
+
+        let whole = exponent.floor();
+        let mantissa = 10f64.powf(exponent - whole);
+        Calculation::from_parts((mantissa * 1e17).round() as i128, whole as i64 - 17).finish()
+    }
+
+    /// Returns the value `t` of the way from `start` to `end`.
+    ///
+    /// A `t` of 0 returns `start` and a `t` of 1 returns `end`. The
+    /// interpolation is computed using a [`Calculation`], so the difference
+    /// between the values never saturates.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let start = Approximint::new(100);
+    /// let end = Approximint::new(200);
+    /// assert_eq!(Approximint::lerp(start, end, 0.25), Approximint::new(125));
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn lerp(start: Self, end: Self, t: f64) -> Self {
+        Calculation::new(end)
+            .sub(start)
+            .mul_f64(t)
+            .add(start)
+            .finish()
+    }
+
+    /// Returns the value `t` of the way from `start` to `end`, interpolating
+    /// the exponents rather than the values.
+    ///
+    /// When `start` and `end` differ by many orders of magnitude, linear
+    /// interpolation spends nearly all of its time near `end`. Interpolating
+    /// in log space instead progresses through each order of magnitude at the
+    /// same rate, such as `1e0`, `1e50`, and `1e100` for a `t` of 0, 0.5, and
+    /// 1.
+    ///
+    /// If either value is zero or the values have different signs, this
+    /// function falls back to [`lerp`](Self::lerp).
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let start = Approximint::ONE;
+    /// let end = Approximint::one_e(100);
+    /// assert_eq!(Approximint::lerp_log(start, end, 0.5), Approximint::one_e(50));
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn lerp_log(start: Self, end: Self, t: f64) -> Self {
+        if start.coefficient == 0
+            || end.coefficient == 0
+            || start.is_negative() != end.is_negative()
+        {
+            return Self::lerp(start, end, t);
+        } else if start.is_negative() {
+            return -Self::lerp_log(-start, -end, t);
+        }
+
+        let start_log = start.log10();
+        Self::ten_powf(start_log + (end.log10() - start_log) * t)
+    }
+
+    /// Returns the base 2 logarithm of this value.
+    ///
+    /// Zero returns negative infinity, and negative values return NaN.
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn log2(self) -> f64 {
+        self.log10() * core::f64::consts::LOG2_10
+    }
+
+    /// Returns the natural logarithm of this value.
+    ///
+    /// Zero returns negative infinity, and negative values return NaN.
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn ln(self) -> f64 {
+        self.log10() * core::f64::consts::LN_10
+    }
+
+    /// Returns a key whose ordering matches the numerical ordering of the
+    /// values it was created from.
+    ///
+    /// Keys are intended to be used in ordered collections such as
+    /// `BTreeMap`. Equal values always produce equal keys, and a key is
+    /// ordered before another key if and only if its value is less than the
+    /// other key's value.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert!(Approximint::new(-1_000_000_000).as_key() < Approximint::new(-5).as_key());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_key(self) -> impl Ord + Copy + core::hash::Hash + Debug {
+        self.key()
+    }
+
+    const fn key(self) -> u64 {
+        const ZERO_KEY: u64 = 1 << 63;
+        let normalized = self.normalized();
+        // When normalized, any value with a larger ten power has a larger
+        // magnitude, allowing the magnitude to be compared as a single
+        // integer.
+        let magnitude =
+            ((normalized.ten_power as u64) << 30) | normalized.coefficient.unsigned_abs() as u64;
+        if normalized.coefficient < 0 {
+            ZERO_KEY - magnitude
+        } else {
+            ZERO_KEY + magnitude
+        }
+    }
 
+    /// Compares `self` and `other` by value.
+    ///
+    /// This function produces the same result as the [`Ord`] implementation,
+    /// but can be used in const contexts.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    ///
+    /// use approximint::Approximint;
+    ///
+    /// const ORDER: Ordering = Approximint::new(-1_000_000_000).cmp_value(Approximint::new(-5));
+    /// assert_eq!(ORDER, Ordering::Less);
+    /// ```
+    #[must_use]
     #[inline]
-    #[expect(clippy::cast_possible_truncation)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        let mut coefficient = i64::from(self.coefficient) * i64::from(rhs.coefficient);
-        let mut ten_power = self.ten_power + rhs.ten_power;
-        while coefficient >= i64::from(Self::COEFFICIENT_LIMIT) {
-            if let Some(next_power) = ten_power.checked_add(1) {
-                ten_power = next_power;
-                coefficient /= 10;
-            } else {
-                coefficient = 999_999_999;
-                break;
-            }
+    pub const fn cmp_value(self, other: Self) -> Ordering {
+        let (lhs, rhs) = (self.key(), other.key());
+        if lhs < rhs {
+            Ordering::Less
+        } else if lhs > rhs {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Compares `self` to the exact value of `other`.
+    fn cmp_integer(self, other: i128) -> Ordering {
+        // 10^38 is larger than the magnitude of any i64 or u64, and the
+        // coefficient of any value with a ten power above 29 can't be
+        // represented in an i128.
+        if self.ten_power > 29 {
+            self.coefficient.cmp(&0)
+        } else {
+            (i128::from(self.coefficient) * 10_i128.pow(self.ten_power)).cmp(&other)
+        }
+    }
+
+    /// Returns the smaller of `self` and `other`.
+    ///
+    /// Unlike [`Ord::min`], this function can be used in const contexts.
+    #[must_use]
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        if other.key() < self.key() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of `self` and `other`.
+    ///
+    /// Unlike [`Ord::max`], this function can be used in const contexts.
+    #[must_use]
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        if other.key() > self.key() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Compares `self` and `other`, treating values that differ by no more
+    /// than `tolerance` as equal.
+    ///
+    /// The values are aligned to the same power of ten before the difference
+    /// is computed, using the same approach as subtraction.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    ///
+    /// use approximint::Approximint;
+    ///
+    /// let price = Approximint::new(1_000);
+    /// let balance = Approximint::new(999);
+    /// assert_eq!(balance.compare_with_tolerance(price, Approximint::ONE), Ordering::Equal);
+    /// assert_eq!(balance.compare_with_tolerance(price, Approximint::ZERO), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub const fn compare_with_tolerance(self, other: Self, tolerance: Self) -> Ordering {
+        let difference = self.const_sub(other);
+        if difference.abs().key() <= tolerance.abs().key() {
+            Ordering::Equal
+        } else if difference.coefficient < 0 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    /// Returns `self` restricted to the range `min..=max`.
+    ///
+    /// Unlike [`Ord::clamp`], this function can be used in const contexts.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// const STORAGE: Approximint = Approximint::one_e(12);
+    /// const ENERGY: Approximint = Approximint::one_e(15).clamp(Approximint::ZERO, STORAGE);
+    /// assert_eq!(ENERGY, STORAGE);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min` is greater than `max`.
+    #[must_use]
+    #[inline]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.key() <= max.key(),
+            "min must be less than or equal to max"
+        );
+        self.max(min).min(max)
+    }
+
+    /// Returns this value with its exponent clamped to `exponents`, preserving
+    /// its significant digits.
+    ///
+    /// The exponent is the power of ten of the value's leading digit, the
+    /// same exponent displayed when formatting using scientific notation. For
+    /// example, clamping `1.5e300` to `..=100` returns `1.5e100`. Zero is
+    /// returned unchanged.
+    ///
+    /// When the exponent is reduced below 8, digits that no longer fit in an
+    /// integer are truncated.
+    #[must_use]
+    pub fn clamp_exponent(self, exponents: impl RangeBounds<u64>) -> Self {
+        const MAX_EXPONENT: u64 = u32::MAX as u64 + 8;
+        if self.coefficient == 0 {
+            return self;
+        }
+
+        let minimum = match exponents.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let maximum = match exponents.end_bound() {
+            Bound::Included(end) => *end,
+            Bound::Excluded(end) => end.saturating_sub(1),
+            Bound::Unbounded => MAX_EXPONENT,
+        };
+        let exponent = self.integer_digits() - 1;
+        let clamped = exponent.min(maximum).max(minimum).min(MAX_EXPONENT);
+
+        // Shift the coefficient so that it contains 9 digits.
+        let mut coefficient = self.coefficient;
+        while coefficient.unsigned_abs() < 100_000_000 {
+            coefficient *= 10;
         }
-        while coefficient <= i64::from(-Self::COEFFICIENT_LIMIT) {
-            if let Some(next_power) = ten_power.checked_add(1) {
-                ten_power = next_power;
+        if let Some(ten_power) = clamped.checked_sub(8) {
+            Self {
+                coefficient,
+                ten_power: u32::try_from(ten_power).expect("clamped to MAX_EXPONENT"),
+            }
+        } else {
+            for _ in clamped..8 {
                 coefficient /= 10;
-            } else {
-                coefficient = -999_999_999;
-                break;
+            }
+            Self {
+                coefficient,
+                ten_power: 0,
             }
         }
+    }
+
+    /// Returns a random value between `low` and `high`, inclusive, picking
+    /// the exponent uniformly before picking the coefficient.
+    ///
+    /// Sampling uniformly between `1` and `1e100` almost always returns a
+    /// value with an exponent of 99. This function instead returns values
+    /// with each exponent between `low` and `high` equally often, which
+    /// suits rewards that scale across many orders of magnitude.
+    ///
+    /// `random` must return uniformly distributed `u64`s. With the `rand`
+    /// crate, `|| rng.next_u64()` can be used.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// // A small xorshift generator used to keep this example dependency free.
+    /// let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    /// let mut random = || {
+    ///     state ^= state << 13;
+    ///     state ^= state >> 7;
+    ///     state ^= state << 17;
+    ///     state
+    /// };
+    ///
+    /// let low = Approximint::new(10);
+    /// let high = Approximint::one_e(100);
+    /// let reward = Approximint::random_log_uniform(&mut random, low, high);
+    /// assert!(reward >= low && reward <= high);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `low` is not positive or if `high` is less
+    /// than `low`.
+    #[must_use]
+    pub fn random_log_uniform(mut random: impl FnMut() -> u64, low: Self, high: Self) -> Self {
+        assert!(low.coefficient > 0, "low must be positive");
+        assert!(
+            low.key() <= high.key(),
+            "low must be less than or equal to high"
+        );
+
+        let low_exponent = low.integer_digits() - 1;
+        let high_exponent = high.integer_digits() - 1;
+        let exponent = low_exponent + random_below(&mut random, high_exponent - low_exponent + 1);
+        // Within a single exponent, every value has the same ten power.
+        let decade = |value: Self| {
+            u64::from(
+                value
+                    .clamp_exponent(exponent..=exponent)
+                    .coefficient
+                    .unsigned_abs(),
+            )
+        };
+        let first = if exponent == low_exponent {
+            decade(low)
+        } else {
+            10_u64.pow(exponent.min(8) as u32)
+        };
+        let last = if exponent == high_exponent {
+            decade(high)
+        } else {
+            10_u64.pow(exponent.min(8) as u32 + 1) - 1
+        };
+        let coefficient = first + random_below(&mut random, last - first + 1);
         Self {
-            coefficient: coefficient as i32,
-            ten_power,
+            coefficient: i32::try_from(coefficient).expect("less than 1e9"),
+            ten_power: u32::try_from(exponent.saturating_sub(8)).expect("at most u32::MAX"),
+        }
+        .normalized()
+    }
+
+    /// Returns the significant digits and exponent of this value.
+    ///
+    /// This function can be used in const contexts, allowing formatted
+    /// values to be computed at compile time.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// const DIGITS: approximint::Digits = Approximint::new(-1_234_567_890).to_digits_const();
+    /// assert_eq!(DIGITS.as_str(), "123456789");
+    /// assert_eq!(DIGITS.exponent, 9);
+    /// assert!(DIGITS.negative);
+    /// ```
+    #[must_use]
+    pub const fn to_digits_const(self) -> Digits {
+        let mut digits = Digits {
+            ascii: [b'0'; 9],
+            len: 0,
+            exponent: 0,
+            negative: self.coefficient < 0,
+        };
+        let mut coefficient = self.coefficient.unsigned_abs();
+        if coefficient == 0 {
+            digits.len = 1;
+            return digits;
+        }
+
+        let mut reversed = [0; 9];
+        while coefficient > 0 {
+            reversed[digits.len as usize] = (coefficient % 10) as u8 + b'0';
+            coefficient /= 10;
+            digits.len += 1;
+        }
+        let mut index = 0;
+        while index < digits.len as usize {
+            digits.ascii[index] = reversed[digits.len as usize - 1 - index];
+            index += 1;
+        }
+        digits.exponent = digits.len as u64 - 1 + self.ten_power as u64;
+        digits
+    }
+
+    /// Returns the sum of the significant digits of this value.
+    ///
+    /// Only the digits stored in the coefficient are summed. Digits that were
+    /// discarded by previous operations are not known, and the sign is
+    /// ignored.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::new(-1_234).digit_sum(), 10);
+    /// ```
+    #[must_use]
+    pub const fn digit_sum(self) -> u32 {
+        let mut remaining = self.coefficient.unsigned_abs();
+        let mut sum = 0;
+        while remaining > 0 {
+            sum += remaining % 10;
+            remaining /= 10;
+        }
+        sum
+    }
+
+    /// Returns the result of repeatedly summing the significant digits of
+    /// this value until a single digit remains.
+    ///
+    /// Like [`digit_sum`](Self::digit_sum), only the digits stored in the
+    /// coefficient are considered.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// assert_eq!(Approximint::new(98_765).digital_root(), 8);
+    /// ```
+    #[must_use]
+    pub const fn digital_root(self) -> u32 {
+        match self.coefficient.unsigned_abs() {
+            0 => 0,
+            coefficient => 1 + (coefficient - 1) % 9,
+        }
+    }
+
+    /// Returns an iterator over the exponents of each power of ten crossed
+    /// when a value changes from `previous` to `next`.
+    ///
+    /// Thresholds are compared against the magnitude of each value, ignoring
+    /// its sign. When the magnitude increases, the exponents are returned in
+    /// ascending order. When the magnitude decreases, the exponents are
+    /// returned in descending order.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let crossed: Vec<u64> =
+    ///     Approximint::crossed_power_of_ten(Approximint::new(50), Approximint::new(5_000))
+    ///         .collect();
+    /// assert_eq!(crossed, [2, 3]);
+    /// ```
+    #[inline]
+    pub const fn crossed_power_of_ten(previous: Self, next: Self) -> CrossedPowersOfTen {
+        let previous = previous.integer_digits();
+        let next = next.integer_digits();
+        if previous <= next {
+            CrossedPowersOfTen {
+                start: previous,
+                end: next,
+                increasing: true,
+            }
+        } else {
+            CrossedPowersOfTen {
+                start: next,
+                end: previous,
+                increasing: false,
+            }
+        }
+    }
+
+    /// Returns the number of digits in the integer portion of this value,
+    /// ignoring its sign.
+    const fn integer_digits(self) -> u64 {
+        let mut coefficient = self.coefficient.unsigned_abs();
+        if coefficient == 0 {
+            return 0;
+        }
+        let mut digits = self.ten_power as u64;
+        while coefficient > 0 {
+            digits += 1;
+            coefficient /= 10;
+        }
+        digits
+    }
+
+    const fn maximize_ten_power(mut self) -> Self {
+        if self.coefficient != 0 {
+            while self.coefficient % 10 == 0 {
+                self.ten_power += 1;
+                self.coefficient /= 10;
+            }
         }
+        self
     }
 }
 
-impl Mul<i32> for Approximint {
-    type Output = Self;
+/// The significant digits of an [`Approximint`].
+///
+/// This type is returned from [`Approximint::to_digits_const`]. The value
+/// represented is `d.ddddddddd * 10^exponent`, where the digits are the
+/// significant digits.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Digits {
+    ascii: [u8; 9],
+    len: u8,
+    /// The power of ten of the first digit.
+    pub exponent: u64,
+    /// If true, the value is negative.
+    pub negative: bool,
+}
+
+impl Digits {
+    /// Returns the significant digits as ASCII bytes, starting with the most
+    /// significant digit.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.ascii.split_at(self.len as usize).0
+    }
+
+    /// Returns the significant digits as a string, starting with the most
+    /// significant digit.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(digits) => digits,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// An iterator over the powers of ten crossed between two values.
+///
+/// This type is returned from [`Approximint::crossed_power_of_ten`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct CrossedPowersOfTen {
+    start: u64,
+    end: u64,
+    increasing: bool,
+}
 
+impl CrossedPowersOfTen {
+    /// Returns true if the magnitude increased between the two values.
+    #[must_use]
     #[inline]
-    fn mul(self, rhs: i32) -> Self::Output {
-        self * Self::new(rhs)
+    pub const fn is_increasing(&self) -> bool {
+        self.increasing
     }
 }
 
-#[cfg(feature = "std")]
-impl Mul<f64> for Approximint {
-    type Output = Self;
+impl Iterator for CrossedPowersOfTen {
+    type Item = u64;
 
     #[inline]
-    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-    fn mul(self, rhs: f64) -> Self::Output {
-        if rhs >= f64::from(Self::COEFFICIENT_LIMIT) {
-            self * Self::approximate(rhs)
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else if self.increasing {
+            self.start += 1;
+            Some(self.start - 1)
         } else {
-            let coefficient = f64::from(self.coefficient) * rhs;
-            let decimals = coefficient.abs().log10();
-            let mut places_to_shift = (9.0 - decimals).floor() as i32;
-            let ten_power =
-                if let Some(ten_power) = self.ten_power.checked_add_signed(-places_to_shift) {
-                    ten_power
-                } else {
-                    places_to_shift = self.ten_power as i32;
-                    0
-                };
+            self.end -= 1;
+            Some(self.end)
+        }
+    }
 
-            let shifted = coefficient * 10f64.powi(places_to_shift);
-            Self {
-                coefficient: shifted.round() as i32,
-                ten_power,
-            }
-            .normalize_overflow()
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else if self.increasing {
+            Some(self.end - 1)
+        } else {
+            Some(self.start)
         }
     }
-}
 
-impl From<u8> for Approximint {
     #[inline]
-    fn from(value: u8) -> Self {
-        Self::new(i32::from(value))
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.end - self.start).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
     }
 }
 
-impl From<u16> for Approximint {
+impl Ord for Approximint {
     #[inline]
-    fn from(value: u16) -> Self {
-        Self::new(i32::from(value))
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_value(*other)
     }
 }
 
-impl From<i32> for Approximint {
+impl PartialOrd for Approximint {
     #[inline]
-    fn from(value: i32) -> Self {
-        Self::new(value)
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl Display for Approximint {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.ten_power > 0 {
-            Display::fmt(&ScientificFormatter::from(*self), f)
-        } else {
-            Display::fmt(&DecimalFormatter::from(*self), f)
-        }
+// Comparisons are only implemented with the Approximint on the left-hand
+// side. Implementing `PartialEq<Approximint>` for primitive integers would
+// break type inference for comparisons between primitives.
+macro_rules! impl_integer_comparisons {
+    ($($int:ty),+) => {
+        $(
+            impl PartialEq<$int> for Approximint {
+                #[inline]
+                fn eq(&self, other: &$int) -> bool {
+                    self.cmp_integer(i128::from(*other)).is_eq()
+                }
+            }
+
+            impl PartialOrd<$int> for Approximint {
+                #[inline]
+                fn partial_cmp(&self, other: &$int) -> Option<Ordering> {
+                    Some(self.cmp_integer(i128::from(*other)))
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_comparisons!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Compares against the exact value of the `f64`. NaN is unordered relative
+/// to every value, and infinities compare beyond [`Approximint::MAX`] and
+/// [`Approximint::MIN`].
+impl PartialEq<f64> for Approximint {
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        cmp::cmp_f64(*self, *other).is_some_and(Ordering::is_eq)
     }
 }
 
-impl Debug for Approximint {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.ten_power > 0 {
-            // For Debug, always show all digits of precision.
-            Display::fmt(
-                &ScientificFormatter::from(*self)
-                    .significant_digits(9)
-                    .truncate_zeroes(),
-                f,
-            )
-        } else {
-            Display::fmt(&DecimalFormatter::from(*self), f)
-        }
+impl PartialOrd<f64> for Approximint {
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        cmp::cmp_f64(*self, *other)
     }
 }
 
-/// A [`Display`] implementation that formats an [`Approximint`] using
-/// scientific notation.
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
-#[must_use]
-pub struct ScientificFormatter {
-    num: Approximint,
-    round: bool,
-    settings: ScientificSettings,
+impl Neg for Approximint {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.const_neg()
+    }
 }
 
-impl ScientificFormatter {
-    /// Sets the character to use between the whole number and decimal digits.
+impl Add for Approximint {
+    type Output = Self;
+
+    /// Returns `self + rhs`, saturating if the result is out of range.
     ///
-    /// By default, the decimal character is `.`.
+    /// When the `strict-debug` feature is enabled in builds with debug
+    /// assertions, this panics if either operand is too small to affect the
+    /// result.
     #[inline]
-    pub fn decimal(mut self, decimal: char) -> Self {
-        self.settings.decimal = decimal;
-        self
+    fn add(self, rhs: Self) -> Self::Output {
+        strict_debug_assert!(
+            self.discards_operand(rhs),
+            "precision lost: {self:?} + {rhs:?} discards an operand"
+        );
+        self.const_add(rhs)
     }
+}
+impl Add<i32> for Approximint {
+    type Output = Self;
 
-    /// Performs rounding on the displayed value.
     #[inline]
-    pub fn rounded(mut self) -> Self {
-        self.round = true;
-        self
+    fn add(self, rhs: i32) -> Self::Output {
+        self + Self::new(rhs)
     }
+}
 
-    /// Sets the number of significant digits to display.
+impl AddAssign for Approximint {
     #[inline]
-    pub fn significant_digits(mut self, digits: u8) -> Self {
-        if self.round {
-            assert!(
-                digits <= 8,
-                "significant digits must be less than 9 when rounding"
-            );
-        } else {
-            assert!(
-                digits <= 9,
-                "significant digits must be less than or equal to 9"
-            );
-        }
-        self.settings.significant_digits = digits;
-        self
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
     }
+}
 
-    /// Prevents displaying trailing zeroes.
+impl AddAssign<i32> for Approximint {
     #[inline]
-    pub fn truncate_zeroes(mut self) -> Self {
-        self.settings.keep_trailing_zeroes = false;
-        self
+    fn add_assign(&mut self, rhs: i32) {
+        *self += Self::new(rhs);
     }
 }
 
-impl From<Approximint> for ScientificFormatter {
+impl Sub for Approximint {
+    type Output = Self;
+
+    /// Returns `self - rhs`, saturating if the result is out of range.
+    ///
+    /// When the `strict-debug` feature is enabled in builds with debug
+    /// assertions, this panics if either operand is too small to affect the
+    /// result.
     #[inline]
-    fn from(num: Approximint) -> Self {
-        Self {
-            num,
-            round: false,
-            settings: ScientificSettings::default(),
-        }
+    fn sub(self, rhs: Self) -> Self::Output {
+        strict_debug_assert!(
+            self.discards_operand(rhs),
+            "precision lost: {self:?} - {rhs:?} discards an operand"
+        );
+        self.const_sub(rhs)
     }
 }
 
-impl Display for ScientificFormatter {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.num.ten_power == 0 && self.num.coefficient == 0 {
-            return f.write_str("0");
-        }
+impl Sub<i32> for Approximint {
+    type Output = Self;
 
-        let mut info = ScientificInfo::new(self.num);
-        if self.round {
-            info.round(self.settings.significant_digits);
-        }
-        info.fmt(f, self.settings)
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        self - Self::new(rhs)
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-struct ScientificInfo {
-    digits: DigitRing,
-    exponent: u64,
-    negative: bool,
+impl SubAssign for Approximint {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
 }
 
-impl ScientificInfo {
-    #[expect(clippy::cast_sign_loss)]
-    fn new(num: Approximint) -> Self {
-        let mut digits = DigitRing::default();
-
-        let (negative, mut coefficient) = if num.coefficient >= 0 {
-            (false, num.coefficient as u32)
-        } else {
-            (true, num.coefficient.unsigned_abs())
-        };
-        let mut exponent = 0;
-        while coefficient > 0 {
-            digits.push_back((coefficient % 10) as u8 + b'0');
-            coefficient /= 10;
-            exponent += 1;
-        }
-
-        let exponent = exponent - 1 + u64::from(num.ten_power);
-        Self {
-            digits,
-            exponent,
-            negative,
-        }
-    }
-
-    fn round(&mut self, significant_digits: u8) {
-        if significant_digits <= 8 {
-            let mut digits_to_round = self
-                .digits
-                .iter_mut_rev()
-                .skip(8 - usize::from(significant_digits));
-            let check_digit = digits_to_round.next().expect("not 0");
-            if (b'5'..=b'9').contains(check_digit) {
-                let mut carry = false;
-                for digit in digits_to_round {
-                    if *digit == b'9' {
-                        *digit = b'0';
-                        carry = true;
-                    } else {
-                        *digit += 1;
-                        carry = false;
-                        break;
-                    }
-                }
-
-                // If we still have the carry flag, we need to push a new 1
-                // digit.
-                if carry {
-                    self.digits.push_back(b'1');
-                    self.exponent += 1;
-                }
-            }
-        }
+impl SubAssign<i32> for Approximint {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self -= Self::new(rhs);
     }
+}
 
-    fn fmt(
-        &self,
-        f: &mut core::fmt::Formatter<'_>,
-        settings: ScientificSettings,
-    ) -> core::fmt::Result {
-        if self.negative {
-            f.write_char('-')?;
-        }
-        let mut digits = self
-            .digits
-            .iter()
-            .take(usize::from(settings.significant_digits))
-            .enumerate();
-        while let Some((index, digit)) = digits.next() {
-            if !settings.keep_trailing_zeroes
-                && index > 0
-                && digit == b'0'
-                && digits.clone().all(|(_, digit)| digit == b'0')
-            {
-                break;
-            }
-
-            if index == 1 {
-                f.write_char(settings.decimal)?;
-            }
-
-            f.write_char(char::from(digit))?;
-        }
+impl Mul for Approximint {
+    type Output = Self;
 
-        write!(f, "e{}", self.exponent)
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.const_mul(rhs)
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct ScientificSettings {
-    decimal: char,
-    significant_digits: u8,
-    keep_trailing_zeroes: bool,
-}
+impl Mul<i32> for Approximint {
+    type Output = Self;
 
-impl Default for ScientificSettings {
     #[inline]
-    fn default() -> Self {
-        Self {
-            decimal: '.',
-            significant_digits: 4,
-            keep_trailing_zeroes: true,
-        }
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * Self::new(rhs)
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
-struct DigitRing {
-    digits: [u8; 9],
-    first: u8,
+impl MulAssign for Approximint {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
 }
 
-impl DigitRing {
-    fn push_back(&mut self, digit: u8) {
-        self.digits[usize::from(self.first)] = digit;
-        self.first += 1;
-        if usize::from(self.first) == self.digits.len() {
-            self.first = 0;
-        }
+impl MulAssign<i32> for Approximint {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self *= Self::new(rhs);
     }
+}
 
-    fn iter(&self) -> DigitRingIter<'_> {
-        self.into_iter()
-    }
+#[cfg(feature = "std")]
+impl Mul<f64> for Approximint {
+    type Output = Self;
 
-    fn iter_mut_rev(&mut self) -> DigitRingIterMutRev<'_> {
-        let (first, second) = self.digits.split_at_mut(usize::from(self.first));
-        DigitRingIterMutRev(second.iter_mut(), first.iter_mut())
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.mul_f64_with(rhs, Rounding::HalfUp)
     }
+}
 
-    const fn len(&self) -> usize {
-        self.digits.len()
+#[cfg(feature = "std")]
+impl MulAssign<f64> for Approximint {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
     }
 }
 
-impl<'a> IntoIterator for &'a DigitRing {
-    type IntoIter = DigitRingIter<'a>;
-    type Item = u8;
+#[cfg(feature = "std")]
+impl Mul<f32> for Approximint {
+    type Output = Self;
 
     #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        let (first, second) = self.digits.split_at(usize::from(self.first));
-        DigitRingIter(second.iter(), first.iter())
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * f64::from(rhs)
     }
 }
 
-#[derive(Clone)]
-struct DigitRingIter<'a>(slice::Iter<'a, u8>, slice::Iter<'a, u8>);
-
-impl<'a> Iterator for DigitRingIter<'a> {
-    type Item = u8;
-
+#[cfg(feature = "std")]
+impl MulAssign<f32> for Approximint {
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let digit = self.1.next_back().or_else(|| self.0.next_back())?;
-            if *digit > 0 {
-                return Some(*digit);
-            }
-        }
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
     }
 }
 
-struct DigitRingIterMutRev<'a>(slice::IterMut<'a, u8>, slice::IterMut<'a, u8>);
+/// Dividing by zero saturates to [`Approximint::MAX`] or
+/// [`Approximint::MIN`], matching multiplication by infinity.
+#[cfg(feature = "std")]
+impl Div<f64> for Approximint {
+    type Output = Self;
+
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn div(self, rhs: f64) -> Self::Output {
+        if rhs == 0. || !rhs.is_finite() {
+            return self * rhs.recip();
+        }
 
-impl<'a> Iterator for DigitRingIterMutRev<'a> {
-    type Item = &'a mut u8;
+        // The reciprocal of a tiny divisor overflows an f64, so the divisor
+        // is instead split into 17 significant digits and a power of ten.
+        // Subnormal divisors are scaled into the normal range first.
+        let (divisor, shift) = if rhs.abs() < 1e-200 {
+            (rhs.abs() * 1e200, 200)
+        } else {
+            (rhs.abs(), 0)
+        };
+        let exponent = divisor.log10().floor() as i32 - 16;
+        let scaled = if exponent < 0 {
+            divisor * 10f64.powi(-exponent)
+        } else {
+            divisor / 10f64.powi(exponent)
+        };
+        let coefficient = i128::from(self.coefficient) * 10_i128.pow(17);
+        Calculation::from_parts(
+            if rhs < 0. { -coefficient } else { coefficient },
+            i64::from(self.ten_power) - 17,
+        )
+        .divided(scaled.round() as u64)
+        .scaled(shift - i64::from(exponent))
+        .finish()
+    }
+}
 
+#[cfg(feature = "std")]
+impl DivAssign<f64> for Approximint {
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.next().or_else(|| self.1.next())
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
     }
 }
 
-/// A [`Display`] implementation for an [`Approximint`] that uses a word list.
-#[derive(Clone, Debug)]
-#[must_use]
-pub struct WordFormatter<'a> {
-    decimal: DecimalFormatter,
-    decimal_before: u32,
-    words: &'a [(u32, &'a str)],
-    round: bool,
-}
-
-static ENGLISH: [(u32, &str); 33] = [
-    (3, "thousand"),
-    (6, "million"),
-    (9, "billion"),
-    (12, "trillion"),
-    (15, "quadrillion"),
-    (18, "quintillion"),
-    (21, "sextillion"),
-    (24, "septillion"),
-    (27, "octillion"),
-    (30, "nonillion"),
-    (33, "decillion"),
-    (36, "undecillion"),
-    (39, "duodecillion"),
-    (42, "tredecillion"),
-    (45, "quattuordecillion"),
-    (48, "quindecillion"),
-    (51, "sexdecillion"),
-    (54, "septendecillion"),
-    (57, "octodecillion"),
-    (60, "novemdecillion"),
-    (63, "vigintillion"),
-    (66, "unvigintillion"),
-    (69, "duovigintillion"),
-    (72, "trevigintillion"),
-    (75, "quattuorvigintillion"),
-    (78, "quinvigintillion"),
-    (81, "sexvigintillion"),
-    (84, "septenvigintillion"),
-    (87, "octovigintillion"),
-    (90, "novemvigintillion"),
-    (93, "trigintillion"),
-    (100, "googol"),
-    (303, "centillion"),
-];
-
-impl WordFormatter<'static> {
-    /// Returns a formatter for the English language.
-    #[inline]
-    pub fn english(num: Approximint) -> Self {
-        Self::new(num, &ENGLISH).decimal_before_10_power(9)
-    }
-}
-
-impl<'a> WordFormatter<'a> {
-    /// Returns a new formatter for `num` using the given `words`.
-    ///
-    /// `words` is a slice of pairs of powers of ten and the associated word.
-    /// For example, here is a portion of the English word list:
+impl Div for Approximint {
+    type Output = Self;
+
+    /// Returns the quotient of `self / rhs`, truncating any fractional
+    /// portion.
     ///
-    /// ```rust
-    /// &[
-    ///     (3, "thousand"),
-    ///     (6, "million"),
-    ///     (9, "billion"),
-    ///     (12, "trillion"),
-    ///     // ...
-    /// ];
-    /// ```
+    /// # Panics
     ///
-    /// The formatter will reduce `num`'s ten-power by the largest matching
-    /// word, and repeat the process until the value is too small for any
-    /// eligible words. The remaining value will then be formatted using decimal
-    /// notation with a single decimal digit when the value is less than 1,000.
+    /// This function panics if `rhs` is zero.
     #[inline]
-    pub fn new(num: Approximint, words: &'static [(u32, &'static str)]) -> Self {
-        Self {
-            decimal: DecimalFormatter::from(num),
-            decimal_before: 0,
-            words,
-            round: false,
-        }
+    fn div(self, rhs: Self) -> Self::Output {
+        self.const_div(rhs)
     }
+}
 
-    /// Performs rounding before formatting the number.
+impl DivAssign for Approximint {
     #[inline]
-    pub fn rounded(mut self) -> Self {
-        self.round = true;
-        self
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
     }
+}
 
-    /// Prevents using words for powers of ten less than or equal to
-    /// `ten_power`.
-    ///
-    /// The default English formatter sets this to 9, preventing values less
-    /// than 1 billion from being converted to words.
-    #[inline]
-    pub fn decimal_before_10_power(mut self, ten_power: u32) -> Self {
-        self.decimal_before = ten_power;
-        self
-    }
+impl Div<i32> for Approximint {
+    type Output = Self;
 
-    /// Sets the character to use between grouped integer digits.
-    ///
-    /// The default separator is `,`.
     #[inline]
-    pub fn separator(mut self, separator: char) -> Self {
-        self.decimal.separator = separator;
-        self
+    fn div(self, rhs: i32) -> Self::Output {
+        self / Self::new(rhs)
     }
+}
 
-    /// Sets the number of integer digits between each separator character.
-    ///
-    /// The default is 3.
+impl DivAssign<i32> for Approximint {
     #[inline]
-    pub fn digits_per_separator(mut self, digits: u8) -> Self {
-        self.decimal.digits_per_separator = digits;
-        self
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
     }
+}
 
-    fn format_info(
-        &self,
-        info: ScientificInfo,
-        f: &mut core::fmt::Formatter<'_>,
-    ) -> core::fmt::Result {
-        if info.negative {
-            f.write_char('-')?;
-        }
-        if info.exponent < u64::from(self.decimal_before) {
-            return Display::fmt(&self.decimal, f);
-        }
-
-        let significant_digits = u16::from(self.decimal.digits_per_separator) + 1;
-        self.format_words(
-            info.exponent,
-            significant_digits,
-            f,
-            |f, exponent, significant_digits| {
-                let digits_per_separator = usize::from(self.decimal.digits_per_separator);
-                let exponent_usize =
-                    usize::try_from(exponent).expect("exponent too large for usize");
-                let separator_offset =
-                    digits_per_separator - 1 - exponent_usize % digits_per_separator;
-                for (index, digit) in info
-                    .digits
-                    .iter()
-                    .take(exponent_usize + 4)
-                    .enumerate()
-                    .take(usize::from(significant_digits))
-                {
-                    if index == exponent_usize + 1 {
-                        if digit == b'0' {
-                            break;
-                        }
-                        f.write_char('.')?;
-                    } else if index > 0 && (index + separator_offset) % digits_per_separator == 0 {
-                        f.write_char(self.decimal.separator)?;
-                    }
-                    f.write_char(char::from(digit))?;
+/// Implements an operator for all combinations of owned and borrowed
+/// operands by delegating to the owned implementation.
+macro_rules! impl_ref_ops {
+    ($($op:ident::$method:ident, $assign:ident::$assign_method:ident);+ $(;)?) => {
+        $(
+            impl $op<&Approximint> for Approximint {
+                type Output = Approximint;
+
+                #[inline]
+                fn $method(self, rhs: &Approximint) -> Self::Output {
+                    $op::$method(self, *rhs)
                 }
-                Ok(())
-            },
-        )
-    }
+            }
 
-    fn format_words(
-        &self,
-        exponent: u64,
-        significant_digits: u16,
-        f: &mut core::fmt::Formatter<'_>,
-        format_exponent: impl FnOnce(&mut core::fmt::Formatter<'_>, u64, u16) -> core::fmt::Result,
-    ) -> core::fmt::Result {
-        // info treats the leading digit as significant, but for the purpose of
-        // this function we need to treat exponent as a count of digits.
-        let word = self
-            .words
-            .windows(2)
-            .skip_while(|words| words[0].0 < self.decimal_before)
-            .find(|words| u64::from(words[0].0) <= exponent && u64::from(words[1].0) > exponent)
-            .map_or_else(
-                || self.words.last().expect("at least one word"),
-                |words| &words[0],
-            );
-        let Some(exponent) = exponent.checked_sub(u64::from(word.0)) else {
-            return format_exponent(f, exponent, significant_digits);
-        };
+            impl $op<Approximint> for &Approximint {
+                type Output = Approximint;
 
-        if exponent < u64::from(significant_digits) {
-            format_exponent(f, exponent, significant_digits)?;
-        } else {
-            if self.round {
-                todo!("round");
+                #[inline]
+                fn $method(self, rhs: Approximint) -> Self::Output {
+                    $op::$method(*self, rhs)
+                }
             }
-            self.format_words(exponent, significant_digits, f, format_exponent)?;
-        }
 
-        f.write_char(' ')?;
-        f.write_str(word.1)
-    }
+            impl $op<&Approximint> for &Approximint {
+                type Output = Approximint;
+
+                #[inline]
+                fn $method(self, rhs: &Approximint) -> Self::Output {
+                    $op::$method(*self, *rhs)
+                }
+            }
+
+            impl $assign<&Approximint> for Approximint {
+                #[inline]
+                fn $assign_method(&mut self, rhs: &Approximint) {
+                    $assign::$assign_method(self, *rhs);
+                }
+            }
+        )+
+    };
 }
 
-impl Display for WordFormatter<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.decimal.num == Approximint::ZERO {
-            f.write_str("0")
-        } else {
-            self.format_info(ScientificInfo::new(self.decimal.num), f)
-        }
+impl_ref_ops!(
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+);
+
+impl Neg for &Approximint {
+    type Output = Approximint;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
     }
 }
 
-/// A [`Display`] implementor for an [`Approximint`] that formats using decimal
-/// notation.
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
-#[must_use]
-pub struct DecimalFormatter {
-    num: Approximint,
-    separator: char,
-    digits_per_separator: u8,
-    scientific_after: u32,
+impl From<u8> for Approximint {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self::new(i32::from(value))
+    }
 }
 
-impl DecimalFormatter {
-    /// Sets the character to use between grouped integer digits.
-    ///
-    /// The default separator is `,`.
+impl From<u16> for Approximint {
     #[inline]
-    pub fn separator(mut self, separator: char) -> Self {
-        self.separator = separator;
-        self
+    fn from(value: u16) -> Self {
+        Self::new(i32::from(value))
     }
+}
 
-    /// Sets the number of integer digits between each separator character.
-    ///
-    /// The default is 3.
+impl From<i32> for Approximint {
     #[inline]
-    pub fn digits_per_separator(mut self, digits: u8) -> Self {
-        self.digits_per_separator = digits;
-        self
+    fn from(value: i32) -> Self {
+        Self::new(value)
     }
 }
 
-impl From<Approximint> for DecimalFormatter {
-    #[inline]
-    fn from(num: Approximint) -> Self {
-        Self {
-            num,
-            separator: ',',
-            digits_per_separator: 3,
-            scientific_after: 30,
-        }
+impl<'a> TryFrom<&'a str> for Approximint {
+    type Error = ParseError<'a>;
+
+    /// Parses a number in decimal or scientific notation, such as
+    /// `-1,234,567` or `1.234e56`.
+    ///
+    /// Grouping separators (`,` and `_`) are ignored in the integer digits.
+    /// Digits beyond the precision of an [`Approximint`] are rounded half
+    /// away from zero, and exponents too large to be represented saturate.
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        parse::number(value)
     }
 }
 
-impl Display for DecimalFormatter {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.num.ten_power == 0 && self.num.coefficient == 0 {
-            return f.write_str("0");
-        } else if self.num.ten_power >= self.scientific_after {
-            return Display::fmt(&ScientificFormatter::from(self.num), f);
-        }
+impl FromStr for Approximint {
+    type Err = FromStrError;
 
-        // To avoid allocations, we need to figure out how many total digits we
-        // have so that we can emit separators along the way.
-        let info = ScientificInfo::new(self.num);
+    /// Parses a number in decimal or scientific notation, such as
+    /// `-1,234,567` or `1.234e56`.
+    ///
+    /// Grouping separators (`,` and `_`) are ignored in the integer digits.
+    /// Digits beyond the precision of an [`Approximint`] are rounded half
+    /// away from zero, and exponents too large to be represented saturate.
+    ///
+    /// ```rust
+    /// use approximint::Approximint;
+    ///
+    /// let value: Approximint = "1,234,567,890".parse().unwrap();
+    /// assert_eq!(value, Approximint::new(1_234_567_890));
+    /// let value: Approximint = "-1.5e3".parse().unwrap();
+    /// assert_eq!(value, Approximint::new(-1_500));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::number(s).map_err(FromStrError::from)
+    }
+}
 
-        if info.negative {
-            f.write_char('-')?;
-        }
+impl Display for Approximint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.display_v1(), f)
+    }
+}
 
-        let digits_per_separator = usize::from(self.digits_per_separator);
-        let exponent_usize = usize::try_from(info.exponent).expect("exponent too large for usize");
-        let separator_offset = if digits_per_separator > 0 {
-            digits_per_separator - 1 - exponent_usize % digits_per_separator
-        } else {
-            0
-        };
+impl Debug for Approximint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.debug_v1(), f)
+    }
+}
 
-        let mut index = 0;
-        for digit in info
-            .digits
-            .iter()
-            .take(info.digits.len().min(exponent_usize + 1))
-        {
-            if self.digits_per_separator > 0
-                && index > 0
-                && (index + separator_offset) % digits_per_separator == 0
-            {
-                f.write_char(self.separator)?;
-            }
-            f.write_char(char::from(digit))?;
-            index += 1;
+/// Returns a uniformly distributed value less than `range` using `random`.
+fn random_below(random: &mut impl FnMut() -> u64, range: u64) -> u64 {
+    loop {
+        let value = random();
+        let remainder = value % range;
+        // Reject values from the final, partial range to avoid bias.
+        if value - remainder <= u64::MAX - (range - 1) {
+            return remainder;
         }
+    }
+}
 
-        for index in index..=exponent_usize {
-            if self.digits_per_separator > 0
-                && (index + separator_offset) % digits_per_separator == 0
-            {
-                f.write_char(self.separator)?;
-            }
-            f.write_char('0')?;
-        }
-        Ok(())
+/// Returns `10^exponent`.
+fn ten_powi(exponent: i32) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        10f64.powi(exponent)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        float::powi(10., exponent)
     }
 }
 
@@ -980,11 +2534,18 @@ impl Approximate for u32 {
     #[inline]
     #[expect(clippy::cast_possible_wrap)]
     fn approximate(mut self) -> Approximint {
+        let original = self;
+        let mut lossy = false;
         let mut ten_power = 0;
         while self >= Approximint::COEFFICIENT_LIMIT as u32 {
             ten_power += 1;
+            lossy |= self % 10 != 0;
             self /= 10;
         }
+        strict_debug_assert!(
+            lossy,
+            "precision lost: approximating {original} drops digits"
+        );
 
         Approximint {
             coefficient: self as i32,
@@ -997,11 +2558,18 @@ impl Approximate for u64 {
     #[inline]
     #[expect(clippy::cast_possible_truncation)]
     fn approximate(mut self) -> Approximint {
+        let original = self;
+        let mut lossy = false;
         let mut ten_power = 0;
         while self >= Approximint::COEFFICIENT_LIMIT as u64 {
             ten_power += 1;
+            lossy |= self % 10 != 0;
             self /= 10;
         }
+        strict_debug_assert!(
+            lossy,
+            "precision lost: approximating {original} drops digits"
+        );
 
         Approximint {
             coefficient: self as i32,
@@ -1021,11 +2589,18 @@ impl Approximate for usize {
     #[cfg(not(any(target_pointer_width = "16", target_pointer_width = "32")))]
     #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     fn approximate(mut self) -> Approximint {
+        let original = self;
+        let mut lossy = false;
         let mut ten_power = 0;
         while self >= Approximint::COEFFICIENT_LIMIT as usize {
             ten_power += 1;
+            lossy |= self % 10 != 0;
             self /= 10;
         }
+        strict_debug_assert!(
+            lossy,
+            "precision lost: approximating {original} drops digits"
+        );
 
         Approximint {
             coefficient: self as i32,
@@ -1038,11 +2613,18 @@ impl Approximate for u128 {
     #[inline]
     #[expect(clippy::cast_possible_truncation)]
     fn approximate(mut self) -> Approximint {
+        let original = self;
+        let mut lossy = false;
         let mut ten_power = 0;
         while self >= Approximint::COEFFICIENT_LIMIT as u128 {
             ten_power += 1;
+            lossy |= self % 10 != 0;
             self /= 10;
         }
+        strict_debug_assert!(
+            lossy,
+            "precision lost: approximating {original} drops digits"
+        );
 
         Approximint {
             coefficient: self as i32,
@@ -1062,15 +2644,23 @@ impl Approximate for i64 {
     #[inline]
     #[expect(clippy::cast_possible_truncation)]
     fn approximate(mut self) -> Approximint {
+        let original = self;
+        let mut lossy = false;
         let mut ten_power = 0;
         while self >= i64::from(Approximint::COEFFICIENT_LIMIT) {
             ten_power += 1;
+            lossy |= self % 10 != 0;
             self /= 10;
         }
         while self <= i64::from(-Approximint::COEFFICIENT_LIMIT) {
             ten_power += 1;
+            lossy |= self % 10 != 0;
             self /= 10;
         }
+        strict_debug_assert!(
+            lossy,
+            "precision lost: approximating {original} drops digits"
+        );
 
         Approximint {
             coefficient: self as i32,
@@ -1081,30 +2671,35 @@ impl Approximate for i64 {
 
 impl Approximate for i128 {
     #[inline]
-    #[expect(clippy::cast_possible_truncation)]
-    fn approximate(mut self) -> Approximint {
-        let mut ten_power = 0;
-        while self >= i128::from(Approximint::COEFFICIENT_LIMIT) {
-            ten_power += 1;
-            self /= 10;
-        }
-        while self <= i128::from(-Approximint::COEFFICIENT_LIMIT) {
-            ten_power += 1;
-            self /= 10;
-        }
-
-        Approximint {
-            coefficient: self as i32,
-            ten_power,
-        }
+    fn approximate(self) -> Approximint {
+        let (approximated, lossy) = Approximint::approximate_i128(self);
+        strict_debug_assert!(lossy, "precision lost: approximating {self} drops digits");
+        approximated
     }
 }
 
+/// Approximates a float, rounding to the nearest representable value.
+///
+/// `NaN` approximates to zero, and infinities saturate to
+/// [`Approximint::MAX`] or [`Approximint::MIN`]. Use
+/// [`TryApproximate::try_approximate`] to reject these values instead.
 #[cfg(feature = "std")]
 impl Approximate for f64 {
     #[inline]
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn approximate(self) -> Approximint {
+        if self.is_nan() || self == 0. {
+            return Approximint::ZERO;
+        } else if self.is_infinite() {
+            return if self < 0. {
+                Approximint::MIN
+            } else {
+                Approximint::MAX
+            };
+        } else if self < 0. {
+            return -(-self).approximate();
+        }
+
         let coefficient = self;
         let decimals = coefficient.log10();
         let mut places_to_shift = (9.0 - decimals).floor() as i32;
@@ -1124,15 +2719,52 @@ impl Approximate for f64 {
     }
 }
 
+/// Approximates a pair of a mantissa and a power of ten: `(mantissa,
+/// exponent)` is approximated as `mantissa * 10^exponent`.
+impl Approximate for (i32, u32) {
+    #[inline]
+    fn approximate(self) -> Approximint {
+        Approximint::new(self.0).with_added_ten_power(self.1)
+    }
+}
+
+/// Approximates a pair of a mantissa and a power of ten: `(mantissa,
+/// exponent)` is approximated as `mantissa * 10^exponent`.
 #[cfg(feature = "std")]
+impl Approximate for (f64, u32) {
+    #[inline]
+    fn approximate(self) -> Approximint {
+        let magnitude = Approximint::one_e(self.1) * self.0.abs();
+        if self.0.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// Converts to the closest `f64` using [`Approximint::to_f64_lossy`].
 impl From<Approximint> for f64 {
+    #[inline]
     fn from(value: Approximint) -> Self {
-        let coefficient = f64::from(value.coefficient) / 1_000_000_000.0;
-        coefficient * 10f64.powi(i32::try_from(value.ten_power).unwrap_or(i32::MAX))
+        value.to_f64_lossy()
+    }
+}
+
+/// Approximates a float without the standard library.
+///
+/// Without the `std` feature, the float's binary representation is converted
+/// directly. The result is within one unit of the ninth significant digit of
+/// the exact value. `NaN` approximates to zero, and infinities saturate to
+/// [`Approximint::MAX`] or [`Approximint::MIN`].
+#[cfg(not(feature = "std"))]
+impl Approximate for f64 {
+    #[inline]
+    fn approximate(self) -> Approximint {
+        float::approximate(self)
     }
 }
 
-#[cfg(feature = "std")]
 impl Approximate for f32 {
     #[inline]
     fn approximate(self) -> Approximint {
@@ -1140,5 +2772,72 @@ impl Approximate for f32 {
     }
 }
 
+/// A value that can be approximated into an [`Approximint`] if it is valid.
+pub trait TryApproximate {
+    /// Returns this value as an integer approximation, or an error if this
+    /// value can't be meaningfully approximated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromFloatError`] if this value is `NaN` or infinite.
+    fn try_approximate(self) -> Result<Approximint, TryFromFloatError>;
+}
+
+/// Approximates a finite float, rejecting `NaN` and infinities.
+///
+/// ```rust
+/// use approximint::{Approximint, TryApproximate, TryFromFloatError};
+///
+/// assert_eq!(
+///     1.5e12.try_approximate(),
+///     Ok(Approximint::new(15) * Approximint::one_e(11))
+/// );
+/// assert_eq!((-2.).try_approximate(), Ok(Approximint::new(-2)));
+/// assert_eq!(f64::NAN.try_approximate(), Err(TryFromFloatError::NotANumber));
+/// assert_eq!(
+///     f64::INFINITY.try_approximate(),
+///     Err(TryFromFloatError::Infinite)
+/// );
+/// ```
+impl TryApproximate for f64 {
+    #[inline]
+    fn try_approximate(self) -> Result<Approximint, TryFromFloatError> {
+        if self.is_nan() {
+            Err(TryFromFloatError::NotANumber)
+        } else if self.is_infinite() {
+            Err(TryFromFloatError::Infinite)
+        } else {
+            Ok(self.approximate())
+        }
+    }
+}
+
+impl TryApproximate for f32 {
+    #[inline]
+    fn try_approximate(self) -> Result<Approximint, TryFromFloatError> {
+        f64::from(self).try_approximate()
+    }
+}
+
+impl TryFrom<f64> for Approximint {
+    type Error = TryFromFloatError;
+
+    /// Approximates a finite float, rejecting `NaN` and infinities.
+    #[inline]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        value.try_approximate()
+    }
+}
+
+impl TryFrom<f32> for Approximint {
+    type Error = TryFromFloatError;
+
+    /// Approximates a finite float, rejecting `NaN` and infinities.
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        value.try_approximate()
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,452 @@
+use core::fmt::Display;
+
+use crate::{Approximint, Calculation};
+
+/// The number of significant digits kept while parsing before the remaining
+/// digits only contribute to the exponent.
+const KEPT_DIGITS: usize = 20;
+
+/// An error parsing an [`Approximint`] from a string.
+///
+/// This error borrows the portion of the input that could not be parsed,
+/// allowing precise error messages to be displayed to users.
+///
+/// ```rust
+/// use approximint::{Approximint, ParseErrorKind};
+///
+/// let err = Approximint::try_from("12x45").unwrap_err();
+/// assert_eq!(err.offset, 2);
+/// assert_eq!(err.token, "x");
+/// assert_eq!(err.kind, ParseErrorKind::InvalidDigit);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct ParseError<'a> {
+    /// The byte offset of [`token`](Self::token) in the input.
+    pub offset: usize,
+    /// The portion of the input that could not be parsed.
+    pub token: &'a str,
+    /// The kind of error encountered.
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)?;
+        if !self.token.is_empty() {
+            write!(f, ": {:?}", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError<'_> {}
+
+/// An error parsing an [`Approximint`] using [`FromStr`](core::str::FromStr).
+///
+/// This error contains the same information as [`ParseError`] without
+/// borrowing the input. Use [`Approximint::try_from`] to receive the portion
+/// of the input that could not be parsed.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct FromStrError {
+    /// The byte offset in the input where the error occurred.
+    pub offset: usize,
+    /// The kind of error encountered.
+    pub kind: ParseErrorKind,
+}
+
+impl From<ParseError<'_>> for FromStrError {
+    #[inline]
+    fn from(err: ParseError<'_>) -> Self {
+        Self {
+            offset: err.offset,
+            kind: err.kind,
+        }
+    }
+}
+
+impl Display for FromStrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromStrError {}
+
+/// The kinds of errors that can occur while parsing an [`Approximint`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input contained no digits.
+    Empty,
+    /// The input contained a character that was not a digit.
+    InvalidDigit,
+    /// The input ended with an exponent marker that was not followed by any
+    /// digits.
+    MissingExponent,
+    /// The input contained a word that was not in the word list.
+    UnknownWord,
+    /// The input contained a component that is too large to be represented.
+    OutOfRange,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParseErrorKind::Empty => "no digits",
+            ParseErrorKind::InvalidDigit => "invalid digit",
+            ParseErrorKind::MissingExponent => "missing exponent",
+            ParseErrorKind::UnknownWord => "unknown word",
+            ParseErrorKind::OutOfRange => "out of range",
+        })
+    }
+}
+
+/// Parses a number in decimal or scientific notation with an optional leading
+/// sign.
+///
+/// Grouping separators (`,` and `_`) are ignored after the first integer
+/// digit. A fraction may follow a `.`, and an exponent may follow an `e` or
+/// `E`. Digits beyond the precision of an [`Approximint`] are rounded half
+/// away from zero, and exponents too large to be represented saturate.
+pub fn number(input: &str) -> Result<Approximint, ParseError<'_>> {
+    let (parsed, offset) = leading_number(input, Marks::DEFAULT, false)?;
+    if offset < input.len() {
+        return Err(invalid_digit(input, offset));
+    }
+    Ok(parsed.finish())
+}
+
+/// Parses a number followed by any number of words from `words`, such as
+/// `123.4 million` or `1 billion googol`.
+///
+/// The number is parsed using the same rules as [`number`]. Each word
+/// multiplies the value by its power of ten, and whitespace is allowed
+/// between the number and each word. When several words match, the longest
+/// word is used.
+///
+/// `marks` controls the decimal and grouping characters accepted in the
+/// number.
+pub fn words<'a>(
+    input: &'a str,
+    words: &[(u32, &str)],
+    marks: Marks,
+) -> Result<Approximint, ParseError<'a>> {
+    let (mut parsed, mut offset) = leading_number(input, marks, true)?;
+    loop {
+        offset += input[offset..].len() - input[offset..].trim_start().len();
+        if offset == input.len() {
+            return Ok(parsed.finish());
+        }
+        let remaining = &input[offset..];
+        let word_end = remaining
+            .find(char::is_whitespace)
+            .unwrap_or(remaining.len());
+        let Some((power, word)) = words
+            .iter()
+            .filter(|(_, word)| !word.is_empty() && remaining.starts_with(word))
+            .max_by_key(|(_, word)| word.len())
+        else {
+            return Err(ParseError {
+                offset,
+                token: &remaining[..word_end],
+                kind: ParseErrorKind::UnknownWord,
+            });
+        };
+        parsed.ten_power = (parsed.ten_power + i64::from(*power)).min(EXPONENT_LIMIT);
+        offset += word.len();
+    }
+}
+
+/// Parses a number followed by an optional suffix from `suffixes`, such as
+/// `1.2K` or `3.4 qa`.
+///
+/// The number is parsed using the same rules as [`number`]. Suffixes are
+/// matched ignoring ASCII case, and may be preceded by whitespace. Surrounding
+/// whitespace is ignored.
+pub fn suffixed<'a>(
+    input: &'a str,
+    suffixes: &[(u32, &str)],
+) -> Result<Approximint, ParseError<'a>> {
+    let trimmed = input.trim();
+    let start = input.len() - input.trim_start().len();
+    let (mut parsed, number_end) =
+        leading_number(trimmed, Marks::DEFAULT, true).map_err(|mut err| {
+            err.offset += start;
+            err
+        })?;
+    let remaining = trimmed[number_end..].trim_start();
+    if !remaining.is_empty() {
+        let Some((power, _)) = suffixes
+            .iter()
+            .find(|(_, suffix)| suffix.eq_ignore_ascii_case(remaining))
+        else {
+            return Err(ParseError {
+                offset: start + trimmed.len() - remaining.len(),
+                token: remaining,
+                kind: ParseErrorKind::UnknownWord,
+            });
+        };
+        parsed.ten_power += i64::from(*power);
+    }
+    Ok(parsed.finish())
+}
+
+/// Parses the canonical `{coefficient}e{exponent}` format produced by
+/// [`CanonicalFormatter`](crate::CanonicalFormatter).
+pub fn canonical(input: &str) -> Result<Approximint, ParseError<'_>> {
+    let bytes = input.as_bytes();
+    let negative = bytes.first() == Some(&b'-');
+    let coefficient_start = usize::from(negative);
+    let (coefficient, mut offset) = canonical_digits(input, coefficient_start, 999_999_999)?;
+    if offset < bytes.len() {
+        if bytes[offset] != b'e' {
+            return Err(invalid_digit(input, offset));
+        }
+        offset += 1;
+    }
+    if offset == bytes.len() {
+        return Err(ParseError {
+            offset,
+            token: "",
+            kind: ParseErrorKind::MissingExponent,
+        });
+    }
+    let (ten_power, offset) = canonical_digits(input, offset, u64::from(u32::MAX))?;
+    if offset < bytes.len() {
+        return Err(invalid_digit(input, offset));
+    }
+
+    let coefficient = i32::try_from(coefficient).expect("limited to 9 digits");
+    Ok(Approximint {
+        coefficient: if negative { -coefficient } else { coefficient },
+        ten_power: u32::try_from(ten_power).expect("limited to u32::MAX"),
+    }
+    .normalized())
+}
+
+/// Parses the run of digits starting at `offset`, returning the value and the
+/// offset after the digits.
+fn canonical_digits(input: &str, start: usize, max: u64) -> Result<(u64, usize), ParseError<'_>> {
+    let bytes = input.as_bytes();
+    let mut value = 0_u64;
+    let mut offset = start;
+    while let Some(&byte) = bytes.get(offset).filter(|byte| byte.is_ascii_digit()) {
+        value = value * 10 + u64::from(byte - b'0');
+        if value > max {
+            let end = input[start..]
+                .find(|ch: char| !ch.is_ascii_digit())
+                .map_or(input.len(), |end| start + end);
+            return Err(ParseError {
+                offset: start,
+                token: &input[start..end],
+                kind: ParseErrorKind::OutOfRange,
+            });
+        }
+        offset += 1;
+    }
+    if offset == start {
+        return Err(if offset == bytes.len() {
+            ParseError {
+                offset,
+                token: "",
+                kind: ParseErrorKind::Empty,
+            }
+        } else {
+            invalid_digit(input, offset)
+        });
+    }
+    Ok((value, offset))
+}
+
+/// The parsed components of a number.
+struct Parsed {
+    coefficient: i128,
+    ten_power: i64,
+}
+
+impl Parsed {
+    fn finish(self) -> Approximint {
+        Calculation::from_parts(self.coefficient, self.ten_power).finish()
+    }
+}
+
+/// Parses the number at the start of `input`, returning the parsed number
+/// and the offset after it.
+///
+/// `marks` controls the decimal and grouping characters accepted. When
+/// `optional_exponent` is true, an exponent marker that isn't followed
+/// by digits is not considered part of the number.
+fn leading_number(
+    input: &str,
+    marks: Marks,
+    optional_exponent: bool,
+) -> Result<(Parsed, usize), ParseError<'_>> {
+    let bytes = input.as_bytes();
+    let (negative, digits_start) = sign(bytes, 0);
+    let mut mantissa = Mantissa::default();
+    let mut offset = digits_start;
+    while let Some(&byte) = bytes.get(offset) {
+        if byte.is_ascii_digit() {
+            mantissa.push_integer(byte);
+        } else if offset == digits_start || !marks.is_separator(char::from(byte)) {
+            break;
+        }
+        offset += 1;
+    }
+    let mut has_digits = offset > digits_start;
+
+    if bytes.get(offset) == Some(&marks.decimal) {
+        offset += 1;
+        let fraction_start = offset;
+        while let Some(&byte) = bytes.get(offset).filter(|byte| byte.is_ascii_digit()) {
+            mantissa.push_fraction(byte);
+            offset += 1;
+        }
+        has_digits |= offset > fraction_start;
+    }
+
+    if !has_digits {
+        return Err(if offset == bytes.len() {
+            ParseError {
+                offset,
+                token: "",
+                kind: ParseErrorKind::Empty,
+            }
+        } else {
+            invalid_digit(input, offset)
+        });
+    }
+
+    let mut exponent = 0_i64;
+    if matches!(bytes.get(offset), Some(b'e' | b'E')) {
+        let marker = offset;
+        let (exponent_negative, exponent_start) = sign(bytes, offset + 1);
+        offset = exponent_start;
+        while let Some(&byte) = bytes.get(offset).filter(|byte| byte.is_ascii_digit()) {
+            exponent = (exponent * 10 + i64::from(byte - b'0')).min(EXPONENT_LIMIT);
+            offset += 1;
+        }
+        if offset == exponent_start {
+            if optional_exponent {
+                offset = marker;
+            } else if offset == bytes.len() {
+                return Err(ParseError {
+                    offset,
+                    token: "",
+                    kind: ParseErrorKind::MissingExponent,
+                });
+            } else {
+                return Err(invalid_digit(input, offset));
+            }
+        }
+        if exponent_negative {
+            exponent = -exponent;
+        }
+    }
+
+    let coefficient = if negative {
+        -mantissa.coefficient
+    } else {
+        mantissa.coefficient
+    };
+    Ok((
+        Parsed {
+            coefficient,
+            ten_power: mantissa.ten_power + exponent,
+        },
+        offset,
+    ))
+}
+
+/// The largest exponent parsed. Larger exponents saturate to this value,
+/// which is still far beyond the range of an [`Approximint`].
+const EXPONENT_LIMIT: i64 = 1 << 40;
+
+/// The significant digits parsed from a number.
+#[derive(Default)]
+struct Mantissa {
+    coefficient: i128,
+    significant: usize,
+    ten_power: i64,
+}
+
+impl Mantissa {
+    fn push_integer(&mut self, digit: u8) {
+        if self.significant < KEPT_DIGITS {
+            self.push(digit);
+        } else {
+            self.ten_power = (self.ten_power + 1).min(EXPONENT_LIMIT);
+        }
+    }
+
+    fn push_fraction(&mut self, digit: u8) {
+        if self.significant < KEPT_DIGITS {
+            self.push(digit);
+            self.ten_power -= 1;
+        }
+    }
+
+    fn push(&mut self, digit: u8) {
+        self.coefficient = self.coefficient * 10 + i128::from(digit - b'0');
+        if self.coefficient > 0 {
+            self.significant += 1;
+        }
+    }
+}
+
+/// Returns whether a sign at `offset` is negative and the offset after the
+/// sign.
+fn sign(bytes: &[u8], offset: usize) -> (bool, usize) {
+    match bytes.get(offset) {
+        Some(b'-') => (true, offset + 1),
+        Some(b'+') => (false, offset + 1),
+        _ => (false, offset),
+    }
+}
+
+/// The characters used to separate portions of a number.
+#[derive(Clone, Copy)]
+pub struct Marks {
+    decimal: u8,
+    separator: u8,
+}
+
+impl Marks {
+    /// Uses `.` as the decimal mark and `,` between groups of digits.
+    pub const DEFAULT: Self = Self {
+        decimal: b'.',
+        separator: b',',
+    };
+    /// Uses `,` as the decimal mark and `.` between groups of digits.
+    pub const GERMAN: Self = Self {
+        decimal: b',',
+        separator: b'.',
+    };
+
+    /// Returns true if `ch` separates groups of digits. `_` is always
+    /// accepted as a separator.
+    fn is_separator(self, ch: char) -> bool {
+        ch == '_' || ch == char::from(self.separator)
+    }
+}
+
+/// Returns true if `ch` separates groups of digits.
+fn is_separator(ch: char) -> bool {
+    matches!(ch, ',' | '_')
+}
+
+/// Returns an [`ParseErrorKind::InvalidDigit`] error for the run of
+/// non-digit characters starting at `offset`.
+fn invalid_digit(input: &str, offset: usize) -> ParseError<'_> {
+    let remaining = &input[offset..];
+    let end = remaining
+        .find(|ch: char| ch.is_ascii_digit() || is_separator(ch))
+        .unwrap_or(remaining.len());
+    ParseError {
+        offset,
+        token: &remaining[..end],
+        kind: ParseErrorKind::InvalidDigit,
+    }
+}
@@ -0,0 +1,64 @@
+//! Helpers for plotting [`Approximint`]s using charting libraries.
+//!
+//! Charting libraries typically store coordinates as `f64`, which can't
+//! represent most [`Approximint`]s. These helpers map values onto a
+//! logarithmic axis using [`Approximint::to_plot_y`], and convert axis
+//! positions back into formatted labels using [`axis_label_formatter`].
+//!
+//! ```rust
+//! use approximint::plot::axis_label_formatter;
+//! use approximint::{Approximint, FormatOptions};
+//!
+//! let y = Approximint::one_e(100).to_plot_y();
+//! let label = axis_label_formatter(FormatOptions::new());
+//! assert_eq!(label(&y), "1.000e100");
+//! ```
+
+use std::string::{String, ToString};
+
+use crate::{Approximint, FormatOptions};
+
+impl Approximint {
+    /// Returns the position of this value on a symmetric logarithmic axis.
+    ///
+    /// The position is `log10(|self| + 1)`, negated for negative values. This
+    /// maps zero to zero, is accurate for values far outside of the range of
+    /// `f64`, and can be reversed using [`from_plot_y`](Self::from_plot_y).
+    #[must_use]
+    pub fn to_plot_y(self) -> f64 {
+        let magnitude = if self.ten_power > 0 {
+            // Adding one doesn't affect values this large.
+            self.abs().log10()
+        } else {
+            f64::from(self.coefficient.unsigned_abs()).ln_1p() / core::f64::consts::LN_10
+        };
+        if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns the value at position `y` of a symmetric logarithmic axis.
+    ///
+    /// This is the inverse of [`to_plot_y`](Self::to_plot_y).
+    #[must_use]
+    pub fn from_plot_y(y: f64) -> Self {
+        let magnitude = Self::ten_powf(y.abs()).const_sub(Self::ONE);
+        if y < 0. {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// Returns a function that formats positions on a symmetric logarithmic axis
+/// using `options`.
+///
+/// Each position is converted using [`Approximint::from_plot_y`] before being
+/// formatted. The returned function matches the signature of label formatters
+/// used by many charting libraries.
+pub fn axis_label_formatter(options: FormatOptions) -> impl Fn(&f64) -> String {
+    move |y| options.format(Approximint::from_plot_y(*y)).to_string()
+}
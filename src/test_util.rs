@@ -0,0 +1,116 @@
+//! Helpers for verifying that formatted values can be parsed.
+//!
+//! These helpers are intended for use in the tests of crates that configure
+//! their own word lists or suffixes, ensuring that every value displayed to
+//! a user can be parsed back into the same value.
+//!
+//! ```rust
+//! use approximint::test_util::{assert_roundtrip, RoundtripNotation};
+//! use approximint::Approximint;
+//!
+//! const WORDS: &[(u32, &str)] = &[(3, "thousand"), (6, "million")];
+//!
+//! let value = Approximint::new(1_234_567);
+//! assert_roundtrip(value, RoundtripNotation::Canonical);
+//! assert_roundtrip(value, RoundtripNotation::Display);
+//! assert_roundtrip(value, RoundtripNotation::Words(WORDS));
+//! ```
+
+use alloc::string::{String, ToString};
+
+use crate::{Approximint, SuffixFormatter, WordFormatter};
+
+/// A notation that [`assert_roundtrip`] formats and parses a value with.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum RoundtripNotation<'a> {
+    /// Formats using [`Approximint::as_canonical`] and parses using
+    /// [`Approximint::from_canonical_str`].
+    Canonical,
+    /// Formats using [`Display`](core::fmt::Display) and parses using
+    /// [`FromStr`](core::str::FromStr).
+    Display,
+    /// Formats using [`WordFormatter::english`] and parses using
+    /// [`Approximint::parse_english`].
+    English,
+    /// Formats using [`WordFormatter::new`] with the given words and parses
+    /// using [`Approximint::parse_words`].
+    Words(&'a [(u32, &'a str)]),
+    /// Formats using [`SuffixFormatter::suffixes`] with the given suffixes
+    /// and parses using [`Approximint::parse_suffixed`].
+    Suffixed(&'static [(u32, &'static str)]),
+}
+
+impl RoundtripNotation<'_> {
+    fn format(self, value: Approximint) -> String {
+        match self {
+            Self::Canonical => value.as_canonical().to_string(),
+            Self::Display => value.to_string(),
+            Self::English => WordFormatter::english(value).to_string(),
+            Self::Words(words) => WordFormatter::new(value, words).to_string(),
+            Self::Suffixed(suffixes) => SuffixFormatter::new(value).suffixes(suffixes).to_string(),
+        }
+    }
+
+    fn parse(self, text: &str) -> Result<Approximint, String> {
+        match self {
+            Self::Canonical => Approximint::from_canonical_str(text).map_err(|err| err.to_string()),
+            Self::Display => text
+                .parse()
+                .map_err(|err: crate::FromStrError| err.to_string()),
+            Self::English => Approximint::parse_english(text).map_err(|err| err.to_string()),
+            Self::Words(words) => {
+                Approximint::parse_words(text, words).map_err(|err| err.to_string())
+            }
+            Self::Suffixed(suffixes) => {
+                Approximint::parse_suffixed(text, suffixes).map_err(|err| err.to_string())
+            }
+        }
+    }
+
+    /// Returns true if `value` is formatted without losing any digits.
+    fn is_exact(self, value: Approximint) -> bool {
+        match self {
+            Self::Canonical => true,
+            Self::Display => value.ten_power == 0,
+            Self::English | Self::Words(_) | Self::Suffixed(_) => false,
+        }
+    }
+}
+
+/// Asserts that `value` formatted using `notation` can be parsed by the
+/// matching parser.
+///
+/// Most notations display fewer digits than an [`Approximint`] stores, so
+/// the parsed value is only required to be displayed identically to `value`
+/// and to have the same number of integer digits, allowing for rounding up
+/// to the next power of ten. Notations that display every digit, such as
+/// [`RoundtripNotation::Canonical`], require the parsed value to equal
+/// `value`.
+///
+/// # Panics
+///
+/// Panics if the formatted text can't be parsed or if the parsed value
+/// doesn't round-trip.
+#[track_caller]
+pub fn assert_roundtrip(value: Approximint, notation: RoundtripNotation<'_>) {
+    let text = notation.format(value);
+    let parsed = match notation.parse(&text) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            panic!("{value:?} formatted as {text:?} using {notation:?} failed to parse: {err}")
+        }
+    };
+    if notation.is_exact(value) {
+        assert_eq!(
+            parsed, value,
+            "{value:?} formatted as {text:?} using {notation:?} parsed as {parsed:?}"
+        );
+    } else {
+        assert!(
+            notation.format(parsed) == text
+                && parsed.integer_digits().abs_diff(value.integer_digits()) <= 1,
+            "{value:?} formatted as {text:?} using {notation:?} parsed as {parsed:?}"
+        );
+    }
+}
@@ -1,9 +1,16 @@
 use std::format;
-use std::string::ToString;
+use std::string::{String, ToString};
 
-use crate::{Approximint, DecimalFormatter, ScientificFormatter, WordFormatter};
+use crate::fmt::ScientificInfo;
+use crate::{
+    Approxfixed, Approximint, Calculation, DecimalFormatter, DisplayBuffer, FormatOptions,
+    FromStrError, LetterFormatter, Locale, LoneDigit, Notation, ParseError, ParseErrorKind,
+    RelativeFormatter, Rounding, ScientificFormatter, ScientificSettings, SmartFormatter,
+    SuffixFormatter, TryApproximate, TryFromApproximintError, TryFromFloatError, WordFormatter,
+};
 
 #[test]
+#[cfg(feature = "std")]
 #[expect(clippy::similar_names)]
 fn basics() {
     let thousand = Approximint::new(1000);
@@ -111,6 +118,21 @@ fn formatting() {
     );
 }
 
+#[test]
+fn scientific_plain_below() {
+    let formatter = |num| ScientificFormatter::from(num).plain_below(6);
+    assert_eq!(formatter(Approximint::new(512)).to_string(), "512");
+    assert_eq!(formatter(Approximint::new(-999_999)).to_string(), "-999999");
+    assert_eq!(
+        formatter(Approximint::new(1_000_000)).to_string(),
+        "1.000e6"
+    );
+    assert_eq!(
+        ScientificFormatter::from(Approximint::new(512)).to_string(),
+        "5.12e2"
+    );
+}
+
 #[test]
 fn english() {
     assert_eq!(
@@ -137,6 +159,7 @@ fn english() {
             .to_string(),
         "123.4 million"
     );
+    #[cfg(feature = "std")]
     assert_eq!(
         WordFormatter::english(Approximint::one_e(100) * core::f64::consts::PI).to_string(),
         "3.1 googol"
@@ -225,3 +248,2756 @@ fn powers() {
         Approximint::new(256) * Approximint::one_e(256)
     );
 }
+
+#[test]
+fn display_buffer() {
+    let mut buffer = DisplayBuffer::<16>::new();
+    let options = FormatOptions::new();
+    assert_eq!(buffer.write(Approximint::new(1_234), &options), "1,234");
+    assert_eq!(buffer.write(Approximint::one_e(100), &options), "1.000e100");
+    let options = FormatOptions::new()
+        .notation(Notation::Scientific)
+        .significant_digits(2)
+        .truncate_zeroes();
+    assert_eq!(buffer.write(Approximint::new(1_234), &options), "1.2e3");
+    let options = FormatOptions::new().notation(Notation::English);
+    assert_eq!(
+        buffer.write(Approximint::one_e(100) * 1_000, &options),
+        "1,000 googol"
+    );
+    // Output that doesn't fit is truncated.
+    assert_eq!(
+        buffer.write(Approximint::one_e(200), &options),
+        "1 googol googol"
+    );
+    assert_eq!(
+        buffer.write(Approximint::one_e(20), &options.notation(Notation::Decimal)),
+        "100,000,000,000,"
+    );
+}
+
+#[test]
+fn crossed_powers() {
+    let crossed = |previous: Approximint, next: Approximint| {
+        Approximint::crossed_power_of_ten(previous, next).collect::<std::vec::Vec<_>>()
+    };
+    assert_eq!(crossed(Approximint::ZERO, Approximint::new(5)), [0]);
+    assert_eq!(crossed(Approximint::new(99), Approximint::new(100)), [2]);
+    assert_eq!(crossed(Approximint::new(100), Approximint::new(999)), []);
+    assert_eq!(
+        crossed(Approximint::new(5_000), Approximint::new(50)),
+        [3, 2]
+    );
+    assert_eq!(
+        crossed(Approximint::one_e(400) * 9, Approximint::one_e(402)),
+        [401, 402]
+    );
+    assert_eq!(
+        Approximint::crossed_power_of_ten(Approximint::ZERO, Approximint::MAX).last(),
+        Some(u64::from(u32::MAX) + 8)
+    );
+    assert!(!Approximint::crossed_power_of_ten(Approximint::MAX, Approximint::ONE).is_increasing());
+}
+
+#[test]
+fn english_compact_residual() {
+    assert_eq!(
+        WordFormatter::english(Approximint::one_e(100) * Approximint::new(999_999_999))
+            .compact_residual()
+            .to_string(),
+        "999.9 million googol"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::one_e(100) * Approximint::new(1_000))
+            .compact_residual()
+            .to_string(),
+        "1,000 googol"
+    );
+    assert_eq!(
+        WordFormatter::new(Approximint::one_e(108) * 9, &[(100, "googol")])
+            .compact_residual()
+            .to_string(),
+        "9e8 googol"
+    );
+    assert_eq!(
+        WordFormatter::new(Approximint::one_e(100) * 123_456_789, &[(100, "googol")])
+            .compact_residual()
+            .to_string(),
+        "1.234e8 googol"
+    );
+}
+
+#[test]
+fn canonical_zero() {
+    let big = Approximint::one_e(20);
+    assert_eq!(big - big, Approximint::ZERO);
+    assert_eq!(big + -big, Approximint::ZERO);
+    assert_eq!(big * Approximint::ZERO, Approximint::ZERO);
+    assert_eq!(Approximint::ZERO * big, Approximint::ZERO);
+    #[cfg(feature = "std")]
+    assert_eq!(Approximint::MAX * 0., Approximint::ZERO);
+    #[cfg(feature = "std")]
+    assert_eq!(big * 1e-30, Approximint::ZERO);
+    assert_eq!((big - big).to_string(), "0");
+}
+
+#[test]
+fn mantissa_exponent_pairs() {
+    assert_eq!(
+        Approximint::approximate((5, 100)),
+        Approximint::new(5) * Approximint::one_e(100)
+    );
+    assert_eq!(
+        Approximint::approximate((-1_234_567_890, 2)),
+        Approximint::new(-1_234_567_890) * 100
+    );
+    assert_eq!(Approximint::approximate((0, 100)), Approximint::ZERO);
+    assert_eq!(
+        Approximint::approximate((5, u32::MAX)).to_string(),
+        "5.000e4294967295"
+    );
+    assert_eq!(
+        Approximint::approximate((2_000_000_000, u32::MAX)),
+        Approximint::MAX
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        Approximint::approximate((1.5, 100)),
+        Approximint::new(15) * Approximint::one_e(99)
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        Approximint::approximate((-2.5e10, u32::MAX)),
+        Approximint::MIN
+    );
+}
+
+#[test]
+fn calculations() {
+    let value = Approximint::new(123_456_789);
+    // Individually, each operation truncates the lowest digits.
+    assert_eq!(
+        value * 10 * 10 + Approximint::new(999),
+        Approximint::approximate(12_345_679_800_u64)
+    );
+    assert_eq!(
+        value
+            .calc()
+            .mul(Approximint::new(10))
+            .mul(Approximint::new(10))
+            .add(Approximint::new(999))
+            .finish(),
+        Approximint::approximate(12_345_679_900_u64)
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        Calculation::new(Approximint::new(3))
+            .mul_f64(0.5)
+            .mul(Approximint::new(3))
+            .finish(),
+        Approximint::new(5)
+    );
+    assert_eq!(
+        Calculation::new(Approximint::one_e(100))
+            .sub(Approximint::one_e(100))
+            .finish(),
+        Approximint::ZERO
+    );
+    assert_eq!(
+        Calculation::new(Approximint::MAX)
+            .mul(Approximint::new(10))
+            .finish(),
+        Approximint::MAX
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        Calculation::new(Approximint::MIN)
+            .mul_f64(f64::INFINITY)
+            .finish(),
+        Approximint::MIN
+    );
+}
+
+#[test]
+fn core_float_conversion() {
+    use crate::float;
+
+    assert_eq!(float::approximate(0.), Approximint::ZERO);
+    assert_eq!(float::approximate(0.49), Approximint::ZERO);
+    assert_eq!(float::approximate(0.5), Approximint::ONE);
+    assert_eq!(float::approximate(123.), Approximint::new(123));
+    assert_eq!(float::approximate(-123.4), Approximint::new(-123));
+    assert_eq!(
+        float::approximate(1_234_567_890.),
+        Approximint::new(1_234_567_890)
+    );
+    assert_eq!(
+        float::approximate(1_234_567_896.),
+        Approximint::new(1_234_567_900)
+    );
+    assert_eq!(float::approximate(1.0e100), Approximint::one_e(100));
+    assert_eq!(
+        float::approximate(f64::MAX),
+        Approximint::approximate(f64::MAX)
+    );
+    assert_eq!(float::approximate(f64::NAN), Approximint::ZERO);
+    assert_eq!(float::approximate(f64::NEG_INFINITY), Approximint::MIN);
+    assert!((float::powi(10., 5) - 100_000.).abs() < f64::EPSILON);
+    assert!((float::powi(2., -2) - 0.25).abs() < f64::EPSILON);
+}
+
+#[test]
+fn division() {
+    let thousand = Approximint::new(1_000);
+    assert_eq!(thousand / Approximint::new(10), Approximint::new(100));
+    assert_eq!(thousand / Approximint::new(3), Approximint::new(333));
+    assert_eq!(thousand / Approximint::new(-3), Approximint::new(-333));
+    assert_eq!(Approximint::new(3) / thousand, Approximint::ZERO);
+    assert_eq!(
+        Approximint::one_e(100) / Approximint::new(3),
+        Approximint::new(333_333_333) * Approximint::one_e(91)
+    );
+    assert_eq!(
+        Approximint::one_e(100) / Approximint::one_e(90),
+        Approximint::one_e(10)
+    );
+    assert_eq!(
+        Approximint::MAX / Approximint::new(2),
+        Approximint::new(499_999_999) * Approximint::one_e(u32::MAX)
+    );
+    let mut value = Approximint::one_e(20);
+    value /= Approximint::one_e(19);
+    assert_eq!(value, Approximint::new(10));
+}
+
+#[test]
+#[should_panic = "attempt to divide by zero"]
+fn division_by_zero() {
+    let _ = Approximint::ONE / Approximint::ZERO;
+}
+
+#[test]
+fn ordered_keys() {
+    let mut values = [
+        Approximint::new(-1_000_000_000),
+        Approximint::MAX,
+        Approximint::new(5),
+        Approximint::ZERO,
+        Approximint::one_e(100),
+        Approximint::new(-5),
+        Approximint::MIN,
+        Approximint::new(999_999_999),
+        Approximint::new(1_000_000_000),
+        -Approximint::one_e(100),
+    ];
+    values.sort_by_key(|value| value.as_key());
+    assert_eq!(
+        values,
+        [
+            Approximint::MIN,
+            -Approximint::one_e(100),
+            Approximint::new(-1_000_000_000),
+            Approximint::new(-5),
+            Approximint::ZERO,
+            Approximint::new(5),
+            Approximint::new(999_999_999),
+            Approximint::new(1_000_000_000),
+            Approximint::one_e(100),
+            Approximint::MAX,
+        ]
+    );
+    assert_eq!(
+        (Approximint::one_e(20) - Approximint::one_e(20)).as_key(),
+        Approximint::ZERO.as_key()
+    );
+}
+
+#[test]
+fn mul_assign() {
+    let mut total = Approximint::new(1_000);
+    total *= Approximint::new(1_000);
+    assert_eq!(total, Approximint::new(1_000_000));
+    total *= 3;
+    assert_eq!(total, Approximint::new(3_000_000));
+    #[cfg(feature = "std")]
+    {
+        total *= 0.5;
+        assert_eq!(total, Approximint::new(1_500_000));
+    }
+}
+
+#[test]
+fn scalar_operations() {
+    let cost = Approximint::new(1_000);
+    assert_eq!(cost * 5, Approximint::new(5_000));
+    assert_eq!(cost / 8, Approximint::new(125));
+    assert_eq!(cost + 1, Approximint::new(1_001));
+    assert_eq!(cost - 1, Approximint::new(999));
+
+    let mut value = cost;
+    value *= 4;
+    value /= 8;
+    value += 1;
+    value -= 2;
+    assert_eq!(value, Approximint::new(499));
+}
+
+#[test]
+fn relative() {
+    let relative = |value: Approximint, reference: Approximint| {
+        RelativeFormatter::new(value, reference).to_string()
+    };
+    let big = Approximint::one_e(1_000);
+    assert_eq!(relative(big, big), "1×");
+    assert_eq!(relative(big * 3, big * 2), "1.5×");
+    assert_eq!(relative(big * 9_999, big), "9,999×");
+    assert_eq!(relative(big * 9_999, big * 10), "999.9×");
+    assert_eq!(relative(big * 99_999, big * 10), "10,000×");
+    assert_eq!(relative(big * 100_000, big * 10), "10,000×");
+    assert_eq!(relative(big, Approximint::one_e(900)), "1.000e100×");
+    assert_eq!(relative(big * 45, big * 100), "45%");
+    assert_eq!(relative(big * 45, big * 1_000), "4.5%");
+    assert_eq!(relative(big, big * 100), "1%");
+    assert_eq!(relative(big, big * 201), "1/201st");
+    assert_eq!(relative(big, big * 1_000), "1/1,000th");
+    assert_eq!(relative(big, big * 1_002), "1/1,002nd");
+    assert_eq!(relative(big, big * 1_013), "1/1,013th");
+    assert_eq!(relative(Approximint::ONE, big), "1/1.000e1000th");
+    assert_eq!(relative(-big * 3, big * 2), "-1.5×");
+    assert_eq!(relative(Approximint::ZERO, big), "0%");
+    assert_eq!(relative(big, Approximint::ZERO), "∞×");
+}
+
+#[test]
+fn reference_operations() {
+    let values = [
+        Approximint::new(1),
+        Approximint::new(2),
+        Approximint::new(3),
+    ];
+    let sum = values
+        .iter()
+        .fold(Approximint::ZERO, |sum, value| sum + value);
+    assert_eq!(sum, Approximint::new(6));
+    let a = &Approximint::new(12);
+    let b = &Approximint::new(4);
+    assert_eq!(a + b, Approximint::new(16));
+    assert_eq!(a - *b, Approximint::new(8));
+    assert_eq!(*a * b, Approximint::new(48));
+    assert_eq!(a / b, Approximint::new(3));
+    assert_eq!(-a, Approximint::new(-12));
+    let mut value = *a;
+    value += b;
+    value -= b;
+    value *= b;
+    value /= b;
+    assert_eq!(value, *a);
+}
+
+#[test]
+fn clamped_exponents() {
+    let value = Approximint::new(15) * Approximint::one_e(299);
+    assert_eq!(
+        value.clamp_exponent(..=100),
+        Approximint::new(15) * Approximint::one_e(99)
+    );
+    assert_eq!(
+        value.clamp_exponent(..101),
+        Approximint::new(15) * Approximint::one_e(99)
+    );
+    assert_eq!(value.clamp_exponent(..=1), Approximint::new(15));
+    assert_eq!(value.clamp_exponent(..=0), Approximint::new(1));
+    assert_eq!(value.clamp_exponent(0..), value);
+    assert_eq!(
+        Approximint::new(-15).clamp_exponent(3..=5),
+        Approximint::new(-1_500)
+    );
+    assert_eq!(
+        Approximint::new(15).clamp_exponent(u64::MAX..),
+        Approximint::new(15) * Approximint::one_e(u32::MAX) * 10_000_000
+    );
+    assert_eq!(Approximint::ZERO.clamp_exponent(5..), Approximint::ZERO);
+}
+
+#[test]
+fn wide_scientific_info() {
+    let mut info = ScientificInfo::<18>::from_parts(false, 999_456_789_012_345_678, 2);
+    assert_eq!(info.exponent, 19);
+    assert_eq!(
+        info.digits.iter().collect::<std::vec::Vec<_>>(),
+        b"999456789012345678"
+    );
+    info.round(2, Rounding::HalfUp);
+    assert_eq!(
+        info.digits.iter().take(3).collect::<std::vec::Vec<_>>(),
+        b"100"
+    );
+    assert_eq!(info.exponent, 20);
+}
+
+#[test]
+fn scientific_approx_marker() {
+    let formatter = |num| ScientificFormatter::from(num).approx_marker("~");
+    assert_eq!(formatter(Approximint::new(123_456)).to_string(), "1.234e5");
+    assert_eq!(
+        formatter(Approximint::new(1_234_567_890)).to_string(),
+        "~1.234e9"
+    );
+    assert_eq!(
+        formatter(Approximint::new(-1_234_567_890)).to_string(),
+        "~-1.234e9"
+    );
+}
+
+#[test]
+fn checked_operations() {
+    let two = Approximint::new(2);
+    assert_eq!(Approximint::MAX.checked_add(Approximint::MAX), None);
+    assert_eq!(Approximint::MIN.checked_sub(Approximint::MAX), None);
+    assert_eq!(Approximint::MAX.checked_mul(two), None);
+    assert_eq!(Approximint::MAX.checked_mul(-two), None);
+    assert_eq!(Approximint::ONE.checked_div(Approximint::ZERO), None);
+    assert_eq!(two.checked_add(two), Some(Approximint::new(4)));
+    assert_eq!(two.checked_sub(two), Some(Approximint::ZERO));
+    assert_eq!(two.checked_mul(two), Some(Approximint::new(4)));
+    assert_eq!(two.checked_div(two), Some(Approximint::ONE));
+    assert_eq!(
+        Approximint::one_e(u32::MAX).checked_mul(Approximint::one_e(u32::MAX)),
+        None
+    );
+    assert_eq!(
+        Approximint::one_e(u32::MAX) * -Approximint::one_e(u32::MAX),
+        Approximint::MIN
+    );
+    assert_eq!(two.strict_mul(two), Approximint::new(4));
+}
+
+#[test]
+#[should_panic = "attempt to multiply with overflow"]
+fn strict_overflow() {
+    let _ = Approximint::MAX.strict_mul(Approximint::new(2));
+}
+
+#[test]
+fn ratio_parts() {
+    let (whole, fraction) = Approximint::new(7).ratio_parts(Approximint::new(2));
+    assert_eq!(whole, Approximint::new(3));
+    assert!((fraction - 0.5).abs() < f64::EPSILON);
+    let (whole, fraction) = Approximint::new(-7).ratio_parts(Approximint::new(2));
+    assert_eq!(whole, Approximint::new(-3));
+    assert!((fraction + 0.5).abs() < f64::EPSILON);
+    let (whole, fraction) = Approximint::one_e(100).ratio_parts(Approximint::one_e(100) * 4);
+    assert_eq!(whole, Approximint::ZERO);
+    assert!((fraction - 0.25).abs() < f64::EPSILON);
+    let (whole, fraction) = Approximint::one_e(100).ratio_parts(Approximint::new(3));
+    assert_eq!(
+        whole,
+        Approximint::new(333_333_333) * Approximint::one_e(91)
+    );
+    assert!(fraction.abs() < f64::EPSILON);
+    let (whole, fraction) = Approximint::ONE.ratio_parts(Approximint::one_e(100));
+    assert_eq!(whole, Approximint::ZERO);
+    assert!(fraction.abs() < f64::EPSILON);
+}
+
+#[test]
+#[cfg(feature = "vector")]
+fn vectors() {
+    use crate::vector::{Vec2A, Vec3A};
+
+    let position = Vec2A::new(Approximint::one_e(100), Approximint::new(5));
+    let velocity = Vec2A::splat(Approximint::new(2));
+    assert_eq!(
+        position * velocity,
+        Vec2A::new(Approximint::one_e(100) * 2, Approximint::new(10))
+    );
+    assert_eq!(
+        position - position + velocity,
+        Vec2A::splat(Approximint::new(2))
+    );
+    let mut position = position.extend(Approximint::ONE);
+    position *= Approximint::new(-3);
+    assert_eq!(
+        position,
+        -Vec3A::new(
+            Approximint::one_e(100) * 3,
+            Approximint::new(15),
+            Approximint::new(3)
+        )
+    );
+    assert_eq!(position.truncate().y, Approximint::new(-15));
+}
+
+#[test]
+fn const_operations() {
+    const MILLION: Approximint = Approximint::new(1_000).const_mul(Approximint::new(1_000));
+    const ALMOST: Approximint = MILLION.const_sub(Approximint::ONE);
+    const NEGATIVE: Approximint = ALMOST.const_add(Approximint::ONE).const_neg();
+    const SATURATED: Approximint = Approximint::MAX.const_add(Approximint::MAX);
+    const QUOTIENT: Approximint = Approximint::one_e(100).const_div(MILLION);
+    const UNDERFLOW: Approximint = Approximint::ONE.const_div(MILLION);
+    const DIV_SATURATED: Approximint = Approximint::MAX.const_div(Approximint::new(-1));
+    assert_eq!(MILLION, Approximint::new(1_000_000));
+    assert_eq!(ALMOST, Approximint::new(999_999));
+    assert_eq!(NEGATIVE, Approximint::new(-1_000_000));
+    assert_eq!(SATURATED, Approximint::MAX);
+    assert_eq!(QUOTIENT, Approximint::one_e(94));
+    assert_eq!(UNDERFLOW, Approximint::ZERO);
+    assert_eq!(DIV_SATURATED, -Approximint::MAX);
+}
+
+#[test]
+fn const_digits() {
+    const ZERO: crate::Digits = Approximint::ZERO.to_digits_const();
+    assert_eq!(ZERO.as_str(), "0");
+    assert_eq!(ZERO.exponent, 0);
+    let digits = Approximint::new(512).to_digits_const();
+    assert_eq!(digits.as_bytes(), b"512");
+    assert_eq!(digits.exponent, 2);
+    assert!(!digits.negative);
+    let digits = Approximint::MAX.to_digits_const();
+    assert_eq!(digits.as_str(), "999999999");
+    assert_eq!(digits.exponent, u64::from(u32::MAX) + 8);
+}
+
+#[test]
+fn try_from_str() {
+    assert_eq!(Approximint::try_from("0"), Ok(Approximint::ZERO));
+    assert_eq!(Approximint::try_from("-000"), Ok(Approximint::ZERO));
+    assert_eq!(Approximint::try_from("+42"), Ok(Approximint::new(42)));
+    assert_eq!(
+        Approximint::try_from("-1234567890"),
+        Ok(Approximint::new(-1_234_567_890))
+    );
+    assert_eq!(
+        Approximint::try_from("1234567895"),
+        Ok(Approximint::new(1_234_567_900))
+    );
+    assert_eq!(
+        Approximint::try_from("1000000000000000000000000000000"),
+        Ok(Approximint::one_e(30))
+    );
+    assert_eq!(
+        Approximint::try_from("12 345"),
+        Err(ParseError {
+            offset: 2,
+            token: " ",
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    assert_eq!(
+        Approximint::try_from("1.5\u{d7}"),
+        Err(ParseError {
+            offset: 3,
+            token: "\u{d7}",
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    assert_eq!(
+        Approximint::try_from("1\u{d7}"),
+        Err(ParseError {
+            offset: 1,
+            token: "\u{d7}",
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    let err = Approximint::try_from("-").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::Empty);
+    assert_eq!(err.offset, 1);
+    assert_eq!(err.to_string(), "no digits at offset 1");
+    assert_eq!(
+        Approximint::try_from("").unwrap_err().kind,
+        ParseErrorKind::Empty
+    );
+}
+
+#[test]
+fn roots() {
+    assert_eq!(Approximint::ZERO.sqrt(), Approximint::ZERO);
+    assert_eq!(Approximint::ONE.sqrt(), Approximint::ONE);
+    assert_eq!(Approximint::new(2).sqrt(), Approximint::ONE);
+    assert_eq!(Approximint::new(3).sqrt(), Approximint::new(2));
+    assert_eq!(Approximint::new(99).sqrt(), Approximint::new(10));
+    assert_eq!(
+        Approximint::new(999_999_999).sqrt(),
+        Approximint::new(31_623)
+    );
+    assert_eq!(
+        Approximint::one_e(21).sqrt(),
+        Approximint::new(316_227_766).with_added_ten_power(2)
+    );
+    assert_eq!(Approximint::new(1_000).cbrt(), Approximint::new(10));
+    assert_eq!(Approximint::new(-8).cbrt(), Approximint::new(-2));
+    assert_eq!(
+        Approximint::one_e(u32::MAX).nth_root(5),
+        Approximint::one_e(858_993_459)
+    );
+    assert_eq!(Approximint::MAX.nth_root(u32::MAX), Approximint::new(10));
+    assert_eq!(Approximint::new(-7).nth_root(1), Approximint::new(-7));
+    // 2^100 = 1.26765060022823e30
+    assert_eq!(
+        Approximint::new(2).powi(100).nth_root(100),
+        Approximint::new(2)
+    );
+    assert_eq!(
+        Approximint::new(1_267_650_600)
+            .with_added_ten_power(21)
+            .sqrt(),
+        Approximint::new(112_589_991).with_added_ten_power(7)
+    );
+}
+
+#[test]
+#[should_panic = "even root of a negative number"]
+fn negative_sqrt() {
+    let _ = Approximint::new(-4).sqrt();
+}
+
+#[test]
+fn fmt_module() {
+    use crate::fmt::prelude::*;
+
+    let num = Approximint::new(123_456).with_added_ten_power(12);
+    assert_eq!(
+        WordFormatter::english(num)
+            .digits_per_separator(0)
+            .to_string(),
+        "123.4 quadrillion"
+    );
+    assert_eq!(
+        DecimalFormatter::from(Approximint::new(1_234_567))
+            .separator('_')
+            .to_string(),
+        "1_234_567"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn logarithms() {
+    assert!(Approximint::ONE.log10().abs() < 1e-12);
+    assert!((Approximint::new(1_000).log10() - 3.).abs() < 1e-12);
+    assert!((Approximint::new(8).log2() - 3.).abs() < 1e-12);
+    assert!((Approximint::new(2).ln() - core::f64::consts::LN_2).abs() < 1e-12);
+    assert!(Approximint::ZERO.log10().is_infinite());
+    assert!(Approximint::new(-1).ln().is_nan());
+    let log = Approximint::MAX.log10();
+    assert!((log - (f64::from(u32::MAX) + 9.)).abs() < 1e-5);
+    assert!((Approximint::MAX.ln() - log * core::f64::consts::LN_10).abs() < 1e-3);
+}
+
+#[test]
+#[cfg(feature = "bench")]
+fn bench_workloads() {
+    for workload in crate::bench::baseline_ops() {
+        assert_ne!(workload.run(), Approximint::ZERO, "{}", workload.name);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn ten_powf() {
+    assert_eq!(Approximint::ten_powf(0.), Approximint::ONE);
+    assert_eq!(Approximint::ten_powf(-0.2), Approximint::ONE);
+    assert_eq!(Approximint::ten_powf(-0.5), Approximint::ZERO);
+    assert_eq!(Approximint::ten_powf(f64::NAN), Approximint::ZERO);
+    assert_eq!(Approximint::ten_powf(f64::INFINITY), Approximint::MAX);
+    assert_eq!(Approximint::ten_powf(9.), Approximint::one_e(9));
+    assert_eq!(
+        Approximint::ten_powf(300.5),
+        Approximint::new(316_227_766).with_added_ten_power(292)
+    );
+    assert_eq!(
+        Approximint::ten_powf(Approximint::MAX.log10()),
+        Approximint::MAX
+    );
+    let value = Approximint::new(-123_456_789).with_added_ten_power(10_000);
+    assert_eq!(Approximint::ten_powf((-value).log10()), -value);
+}
+
+#[test]
+fn signs() {
+    const NEGATIVE: Approximint = Approximint::MIN;
+    const _: () = assert!(NEGATIVE.is_negative() && !NEGATIVE.is_positive());
+    assert_eq!(NEGATIVE.abs(), Approximint::MAX);
+    assert_eq!(NEGATIVE.signum(), Approximint::new(-1));
+    assert_eq!(Approximint::MAX.abs(), Approximint::MAX);
+    assert_eq!(Approximint::MAX.signum(), Approximint::ONE);
+    assert_eq!(Approximint::ZERO.signum(), Approximint::ZERO);
+    assert!(Approximint::ZERO.is_zero());
+    assert!(!Approximint::ZERO.is_positive());
+    assert!(!Approximint::ZERO.is_negative());
+    assert!(!Approximint::ONE.is_zero());
+}
+
+#[test]
+fn english_short() {
+    let short = |value: Approximint| WordFormatter::english_short(value).to_string();
+    assert_eq!(short(Approximint::ZERO), "0");
+    assert_eq!(short(Approximint::new(999)), "999");
+    assert_eq!(short(Approximint::new(1_000)), "1K");
+    assert_eq!(short(Approximint::new(-1_500)), "-1.5K");
+    assert_eq!(short(Approximint::new(1_234_567)), "1.23M");
+    assert_eq!(short(Approximint::new(1_235_000)), "1.23M");
+    assert_eq!(short(Approximint::new(1_235_001)), "1.24M");
+    assert_eq!(short(Approximint::new(999_994)), "999.99K");
+    assert_eq!(short(Approximint::new(999_996)), "1M");
+    assert_eq!(short(Approximint::one_e(12) * 42), "42T");
+    assert_eq!(short(Approximint::one_e(33) * 123), "123Dc");
+    assert_eq!(
+        WordFormatter::english(Approximint::new(123_456_789))
+            .decimal_before_10_power(6)
+            .decimal_places(3)
+            .rounding(Rounding::HalfUp)
+            .to_string(),
+        "123.457 million"
+    );
+}
+
+#[test]
+fn min_max_clamp() {
+    const SMALLEST: Approximint = Approximint::MIN.min(Approximint::new(-1));
+    assert_eq!(SMALLEST, Approximint::MIN);
+    let big = Approximint::one_e(20);
+    assert_eq!(big.min(-big), -big);
+    assert_eq!((-big).max(Approximint::new(-1)), Approximint::new(-1));
+    assert_eq!(big.max(Approximint::new(5)), big);
+    assert_eq!(
+        Approximint::new(-5).clamp(Approximint::ZERO, big),
+        Approximint::ZERO
+    );
+    assert_eq!(
+        Approximint::new(5).clamp(Approximint::ZERO, big),
+        Approximint::new(5)
+    );
+    assert_eq!(Approximint::MAX.clamp(-big, big), big);
+}
+
+#[test]
+#[should_panic = "min must be less than or equal to max"]
+fn invalid_clamp() {
+    let _ = Approximint::ZERO.clamp(Approximint::ONE, Approximint::new(-1));
+}
+
+#[test]
+fn midpoint_and_average() {
+    assert_eq!(
+        Approximint::MAX.midpoint(Approximint::MAX),
+        Approximint::MAX
+    );
+    assert_eq!(
+        Approximint::MIN.midpoint(Approximint::MAX),
+        Approximint::ZERO
+    );
+    assert_eq!(
+        Approximint::new(-3).midpoint(Approximint::ZERO),
+        Approximint::new(-2)
+    );
+    assert_eq!(
+        Approximint::one_e(100).midpoint(Approximint::one_e(101)),
+        Approximint::new(55).with_added_ten_power(99)
+    );
+    assert_eq!(Approximint::average(&[]), None);
+    assert_eq!(
+        Approximint::average(&[Approximint::MAX, Approximint::MAX, Approximint::MAX]),
+        Some(Approximint::MAX)
+    );
+    assert_eq!(
+        Approximint::average(&[
+            Approximint::new(1),
+            Approximint::new(2),
+            Approximint::new(4)
+        ]),
+        Some(Approximint::new(2))
+    );
+    assert_eq!(
+        Approximint::average(&[Approximint::new(10), Approximint::new(20)]),
+        Some(Approximint::new(15))
+    );
+}
+
+#[test]
+fn json_numbers() {
+    let json = |value: Approximint| {
+        let mut json = String::new();
+        value.write_json_number(&mut json).unwrap();
+        json
+    };
+    assert_eq!(json(Approximint::ZERO), "0");
+    assert_eq!(json(Approximint::new(-1_234_567)), "-1234567");
+    assert_eq!(
+        json(Approximint::new(999_999_999).with_added_ten_power(9)),
+        "999999999000000000"
+    );
+    assert_eq!(
+        json(Approximint::new(100_000_000).with_added_ten_power(10)),
+        "\"1e18\""
+    );
+    assert_eq!(json(Approximint::MIN), "\"-9.99999999e4294967303\"");
+}
+
+#[test]
+fn tolerant_comparison() {
+    use core::cmp::Ordering;
+
+    let huge = Approximint::one_e(1_000);
+    assert_eq!(
+        Approximint::MAX.compare_with_tolerance(Approximint::MIN, huge),
+        Ordering::Greater
+    );
+    assert_eq!(
+        (huge * 2).compare_with_tolerance(huge * 3, huge),
+        Ordering::Equal
+    );
+    assert_eq!(
+        (huge * 2).compare_with_tolerance(huge * 3, -huge),
+        Ordering::Equal
+    );
+    assert_eq!(
+        (huge * 2).compare_with_tolerance(huge * 4, huge),
+        Ordering::Less
+    );
+    assert_eq!(
+        Approximint::new(5).compare_with_tolerance(Approximint::new(-5), Approximint::new(9)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        huge.compare_with_tolerance(huge.const_add(Approximint::ONE), Approximint::ZERO),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn abs_diff() {
+    assert_eq!(
+        Approximint::MIN.abs_diff(Approximint::MAX),
+        Approximint::MAX
+    );
+    assert_eq!(
+        Approximint::MAX.abs_diff(Approximint::MIN),
+        Approximint::MAX
+    );
+    assert_eq!(
+        Approximint::new(-3).abs_diff(Approximint::new(4)),
+        Approximint::new(7)
+    );
+    assert_eq!(
+        Approximint::one_e(50).abs_diff(Approximint::one_e(50)),
+        Approximint::ZERO
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn lerp() {
+    let huge = Approximint::one_e(500);
+    assert_eq!(
+        Approximint::lerp(Approximint::MIN, Approximint::MAX, 0.5),
+        Approximint::ZERO
+    );
+    assert_eq!(
+        Approximint::lerp(Approximint::ZERO, huge, 0.),
+        Approximint::ZERO
+    );
+    assert_eq!(Approximint::lerp(Approximint::ZERO, huge, 1.), huge);
+    assert_eq!(Approximint::lerp(huge, Approximint::ZERO, 0.75), huge / 4);
+    assert_eq!(Approximint::lerp(Approximint::ONE, huge, 0.5), huge / 2);
+    assert_eq!(
+        Approximint::lerp_log(Approximint::ONE, huge, 0.5),
+        Approximint::one_e(250)
+    );
+    assert_eq!(
+        Approximint::lerp_log(-Approximint::ONE, -huge, 0.2),
+        -Approximint::one_e(100)
+    );
+    assert_eq!(
+        Approximint::lerp_log(Approximint::ZERO, Approximint::new(10), 0.5),
+        Approximint::new(5)
+    );
+}
+
+#[test]
+fn copysign() {
+    let huge = Approximint::one_e(1_000);
+    assert_eq!(huge.copysign(Approximint::MIN), -huge);
+    assert_eq!((-huge).copysign(Approximint::ONE), huge);
+    assert_eq!(huge.copysign(huge), huge);
+    assert_eq!(Approximint::ZERO.copysign(-huge), Approximint::ZERO);
+    assert_eq!((-huge).signum(), Approximint::new(-1));
+}
+
+#[test]
+fn ratio() {
+    let huge = Approximint::one_e(u32::MAX);
+    assert!((huge.ratio(huge * 2) - 0.5).abs() < f64::EPSILON);
+    assert!((Approximint::new(-3).ratio(Approximint::new(4)) + 0.75).abs() < f64::EPSILON);
+    assert!(Approximint::ZERO.ratio(huge).abs() < f64::EPSILON);
+    assert!(Approximint::ONE.ratio(huge).abs() < f64::EPSILON);
+    assert!((huge.ratio(Approximint::ONE) - f64::MAX).abs() < f64::EPSILON);
+    assert!((huge.ratio(-Approximint::ONE) - f64::MIN).abs() < f64::EPSILON);
+    assert!((Approximint::one_e(310).ratio(Approximint::new(100)) - 1e308).abs() < 1e293);
+    let tiny = Approximint::new(5).ratio(Approximint::one_e(320));
+    assert!(tiny > 0. && tiny < 1e-318);
+}
+
+#[test]
+fn digit_sums() {
+    assert_eq!(Approximint::ZERO.digit_sum(), 0);
+    assert_eq!(Approximint::ZERO.digital_root(), 0);
+    assert_eq!(Approximint::MAX.digit_sum(), 81);
+    assert_eq!(Approximint::MIN.digital_root(), 9);
+    let value = Approximint::new(123_456_789).with_added_ten_power(1_000);
+    assert_eq!(value.digit_sum(), 45);
+    assert_eq!(value.digital_root(), 9);
+    assert_eq!(Approximint::new(1_000).digit_sum(), 1);
+    assert_eq!(Approximint::new(38).digital_root(), 2);
+}
+
+#[test]
+fn scientific_lone_digit() {
+    let value = Approximint::new(-2).with_added_ten_power(9);
+    let formatter = value.as_scientific();
+    assert_eq!(formatter.to_string(), "-2.000e9");
+    assert_eq!(
+        formatter.lone_digit(LoneDigit::Point).to_string(),
+        "-2.000e9"
+    );
+    let truncated = formatter.truncate_zeroes().decimal(',');
+    assert_eq!(truncated.to_string(), "-2e9");
+    assert_eq!(truncated.lone_digit(LoneDigit::Point).to_string(), "-2,e9");
+    assert_eq!(
+        truncated.lone_digit(LoneDigit::PointZero).to_string(),
+        "-2,0e9"
+    );
+    assert_eq!(
+        Approximint::new(25)
+            .with_added_ten_power(9)
+            .as_scientific()
+            .significant_digits(1)
+            .lone_digit(LoneDigit::PointZero)
+            .to_string(),
+        "2.0e10"
+    );
+    assert_eq!(
+        Approximint::new(25)
+            .with_added_ten_power(9)
+            .as_scientific()
+            .truncate_zeroes()
+            .lone_digit(LoneDigit::PointZero)
+            .to_string(),
+        "2.5e10"
+    );
+}
+
+#[test]
+#[cfg(feature = "plot")]
+fn plot_coordinates() {
+    use crate::plot::axis_label_formatter;
+
+    assert!(Approximint::ZERO.to_plot_y().abs() < f64::EPSILON);
+    assert!((Approximint::new(9).to_plot_y() - 1.).abs() < f64::EPSILON);
+    assert!((Approximint::new(-99).to_plot_y() + 2.).abs() < f64::EPSILON);
+    for value in [
+        Approximint::ZERO,
+        Approximint::new(5),
+        Approximint::new(-123_456),
+        Approximint::one_e(1_000) * 42,
+    ] {
+        assert_eq!(Approximint::from_plot_y(value.to_plot_y()), value);
+    }
+    let label = axis_label_formatter(FormatOptions::new().notation(Notation::English));
+    assert_eq!(label(&12.), "1 trillion");
+    assert_eq!(label(&-3.), "-999");
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("1234567890".parse(), Ok(Approximint::new(1_234_567_890)));
+    assert_eq!("-1,000,000".parse(), Ok(Approximint::new(-1_000_000)));
+    assert_eq!("1_000".parse(), Ok(Approximint::new(1_000)));
+    assert_eq!(
+        "99,999,999,999,999,999,999".parse(),
+        Ok(Approximint::one_e(20))
+    );
+    assert_eq!(
+        ",1".parse::<Approximint>(),
+        Err(FromStrError {
+            offset: 0,
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    assert_eq!(
+        Approximint::try_from("1,2x3"),
+        Err(ParseError {
+            offset: 3,
+            token: "x",
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    assert_eq!(
+        "1x5".parse::<Approximint>().unwrap_err().to_string(),
+        "invalid digit at offset 1"
+    );
+}
+
+#[test]
+fn parse_scientific() {
+    let parse = |s: &str| s.parse::<Approximint>();
+    assert_eq!(
+        parse("1.234e56"),
+        Ok(Approximint::new(1_234).with_added_ten_power(53))
+    );
+    assert_eq!(
+        parse("-3.2e10"),
+        Ok(Approximint::new(-32).with_added_ten_power(9))
+    );
+    assert_eq!(parse("1.5"), Ok(Approximint::new(2)));
+    assert_eq!(parse(".4"), Ok(Approximint::ZERO));
+    assert_eq!(parse("2."), Ok(Approximint::new(2)));
+    assert_eq!(parse("5E-1"), Ok(Approximint::ONE));
+    assert_eq!(parse("1e-9999999999999999999999"), Ok(Approximint::ZERO));
+    assert_eq!(
+        parse("9.99E+4294967295"),
+        Ok(Approximint::new(999).with_added_ten_power(u32::MAX - 2))
+    );
+    assert_eq!(parse("1e4294967305"), Ok(Approximint::MAX));
+    assert_eq!(parse("-1e99999999999999999999999"), Ok(Approximint::MIN));
+    for value in [
+        Approximint::MAX,
+        Approximint::MIN,
+        Approximint::new(-123_456_789).with_added_ten_power(42),
+    ] {
+        let formatted = value
+            .as_scientific()
+            .significant_digits(9)
+            .truncate_zeroes()
+            .to_string();
+        assert_eq!(parse(&formatted), Ok(value));
+    }
+    assert_eq!(
+        Approximint::try_from("1e"),
+        Err(ParseError {
+            offset: 2,
+            token: "",
+            kind: ParseErrorKind::MissingExponent,
+        })
+    );
+    assert_eq!(
+        Approximint::try_from("1e+x"),
+        Err(ParseError {
+            offset: 3,
+            token: "x",
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    assert_eq!(
+        Approximint::try_from("1.2.3"),
+        Err(ParseError {
+            offset: 3,
+            token: ".",
+            kind: ParseErrorKind::InvalidDigit,
+        })
+    );
+    assert_eq!(parse(".").unwrap_err().kind, ParseErrorKind::Empty);
+}
+
+#[test]
+fn geometric_midpoint() {
+    assert_eq!(
+        Approximint::MAX.midpoint_geometric(Approximint::MAX),
+        Approximint::MAX
+    );
+    assert_eq!(
+        Approximint::ONE.midpoint_geometric(Approximint::one_e(u32::MAX)),
+        Approximint::new(316_227_766).with_added_ten_power(u32::MAX / 2 - 8)
+    );
+    assert_eq!(
+        Approximint::new(-4).midpoint_geometric(Approximint::new(-9)),
+        Approximint::new(-6)
+    );
+    assert_eq!(
+        Approximint::ZERO.midpoint_geometric(Approximint::new(-9)),
+        Approximint::ZERO
+    );
+}
+
+#[test]
+#[should_panic = "geometric midpoint of values with different signs"]
+fn geometric_midpoint_signs() {
+    let _ = Approximint::new(-4).midpoint_geometric(Approximint::new(9));
+}
+
+#[test]
+fn display_builder() {
+    let n = Approximint::new(999_960_000).with_added_ten_power(3);
+    assert_eq!(n.display().to_string(), n.to_string());
+    assert_eq!(n.display().words().to_string(), "999.9 billion");
+    assert_eq!(n.display().words().rounded().to_string(), "1 trillion");
+    assert_eq!(n.display().words().digits(3).to_string(), "999.96 billion");
+    assert_eq!(
+        n.display().scientific().digits(2).rounded().to_string(),
+        "1.00e12"
+    );
+    assert_eq!(
+        Approximint::new(1_234_567)
+            .display()
+            .separator('_')
+            .plus_sign()
+            .to_string(),
+        "+1_234_567"
+    );
+    assert_eq!((-n).display().plus_sign().to_string(), (-n).to_string());
+    assert_eq!(Approximint::ZERO.display().plus_sign().to_string(), "0");
+}
+
+#[test]
+fn parse_words() {
+    for value in [
+        Approximint::new(999_999_999),
+        Approximint::new(123_400_000).with_added_ten_power(3),
+        Approximint::new(-150_000_000).with_added_ten_power(100),
+        Approximint::one_e(400),
+    ] {
+        let formatted = value.as_english().to_string();
+        assert_eq!(
+            Approximint::parse_english(&formatted),
+            Ok(value),
+            "{formatted}"
+        );
+    }
+    assert_eq!(
+        Approximint::parse_english("1 billion googol"),
+        Ok(Approximint::one_e(109))
+    );
+    assert_eq!(
+        Approximint::parse_english("-2.5K"),
+        Ok(Approximint::new(-2_500))
+    );
+    assert_eq!(
+        Approximint::parse_english("9.999e8 googol"),
+        Ok(Approximint::new(999_900_000).with_added_ten_power(100))
+    );
+
+    let words: &[(u32, &str)] = &[(2, "e"), (4, "eons")];
+    assert_eq!(
+        Approximint::parse_words("3eons e", words),
+        Ok(Approximint::new(3_000_000))
+    );
+
+    let err = Approximint::parse_english("12 gazillion").unwrap_err();
+    assert_eq!(err.offset, 3);
+    assert_eq!(err.token, "gazillion");
+    assert_eq!(err.kind, ParseErrorKind::UnknownWord);
+    assert_eq!(
+        Approximint::parse_english("million").unwrap_err().kind,
+        ParseErrorKind::InvalidDigit
+    );
+}
+
+#[test]
+fn approxfixed() {
+    type Cents = Approxfixed<2>;
+    let price = Cents::from_parts(12, 5);
+    assert_eq!(price.to_string(), "12.05");
+    assert_eq!((-price).to_string(), "-12.05");
+    assert_eq!(Cents::from_parts(-1, 50).to_string(), "-1.50");
+    assert_eq!(Cents::from_parts(0, 50).to_approximint(), Approximint::ZERO);
+    assert_eq!(
+        (price * 100_000)
+            .format(FormatOptions::new().separator('_').plus_sign())
+            .to_string(),
+        "+1_205_000.00"
+    );
+
+    let large = price * 10_000_000;
+    assert!(!large.is_exact());
+    assert_eq!(large.to_approximint(), Approximint::new(120_500_000));
+    assert_eq!(large.to_string(), "120,500,000");
+    assert_eq!(
+        Cents::new(Approximint::one_e(20)).to_string(),
+        Approximint::one_e(20).to_string()
+    );
+    assert_eq!(Approximint::from(large - large), Approximint::ZERO);
+    assert_eq!(Approxfixed::<0>::new(Approximint::new(5)).to_string(), "5");
+}
+
+#[test]
+fn parse_suffixes() {
+    assert_eq!(
+        Approximint::parse_short("1.5B"),
+        Ok(Approximint::new(15) * Approximint::one_e(8))
+    );
+    assert_eq!(Approximint::parse_short("42"), Ok(Approximint::new(42)));
+    assert_eq!(
+        Approximint::parse_short("-7 qi"),
+        Ok(Approximint::new(-7) * Approximint::one_e(18))
+    );
+    assert_eq!(
+        Approximint::parse_short("2.5e3k"),
+        Ok(Approximint::new(2_500_000))
+    );
+
+    let err = Approximint::parse_short("  1.5 zz").unwrap_err();
+    assert_eq!(err.offset, 6);
+    assert_eq!(err.token, "zz");
+    assert_eq!(err.kind, ParseErrorKind::UnknownWord);
+    let err = Approximint::parse_short(" x1").unwrap_err();
+    assert_eq!(err.offset, 1);
+    assert_eq!(err.kind, ParseErrorKind::InvalidDigit);
+    assert_eq!(
+        Approximint::parse_short("1KK").unwrap_err().kind,
+        ParseErrorKind::UnknownWord
+    );
+}
+
+#[test]
+fn canonical_round_trip() {
+    for value in [
+        Approximint::ZERO,
+        Approximint::new(-42),
+        Approximint::new(999_999_999),
+        Approximint::new(100_000_001).with_added_ten_power(1),
+        Approximint::MAX,
+        Approximint::MIN,
+    ] {
+        let canonical = value.as_canonical().to_string();
+        assert_eq!(
+            Approximint::from_canonical_str(&canonical),
+            Ok(value),
+            "{canonical}"
+        );
+    }
+    assert_eq!(
+        Approximint::MAX.as_canonical().to_string(),
+        "999999999e4294967295"
+    );
+
+    for (input, offset, token, kind) in [
+        ("", 0, "", ParseErrorKind::Empty),
+        ("12", 2, "", ParseErrorKind::MissingExponent),
+        ("12e", 3, "", ParseErrorKind::MissingExponent),
+        ("12e-3", 3, "-", ParseErrorKind::InvalidDigit),
+        ("1.5e3", 1, ".", ParseErrorKind::InvalidDigit),
+        ("1e3x", 3, "x", ParseErrorKind::InvalidDigit),
+        ("-1000000000e0", 1, "1000000000", ParseErrorKind::OutOfRange),
+        ("1e4294967296", 2, "4294967296", ParseErrorKind::OutOfRange),
+    ] {
+        assert_eq!(
+            Approximint::from_canonical_str(input),
+            Err(ParseError {
+                offset,
+                token,
+                kind
+            }),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn layout_chunks() {
+    use std::vec::Vec;
+
+    use crate::ChunkRole;
+
+    let chunks: Vec<_> = Approximint::new(-1_234_567)
+        .layout_chunks(&FormatOptions::new().plus_sign())
+        .collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("-"), ChunkRole::Sign),
+            (String::from("1"), ChunkRole::Digits),
+            (String::from(","), ChunkRole::Separator),
+            (String::from("234"), ChunkRole::Digits),
+            (String::from(","), ChunkRole::Separator),
+            (String::from("567"), ChunkRole::Digits),
+        ]
+    );
+
+    let value = Approximint::new(123_456_789).with_added_ten_power(6);
+    let chunks = value.layout_chunks(&FormatOptions::new().notation(Notation::English));
+    assert_eq!(chunks.as_str(), "123.4 trillion");
+    let roles: Vec<_> = chunks.map(|(_, role)| role).collect();
+    assert_eq!(
+        roles,
+        [
+            ChunkRole::Digits,
+            ChunkRole::Decimal,
+            ChunkRole::Digits,
+            ChunkRole::Space,
+            ChunkRole::Word
+        ]
+    );
+
+    let options = FormatOptions::new().decimal(',').plus_sign();
+    let text: Vec<_> = value.layout_chunks(&options).collect();
+    assert_eq!(
+        text,
+        [
+            (String::from("+"), ChunkRole::Sign),
+            (String::from("1"), ChunkRole::Digits),
+            (String::from(","), ChunkRole::Decimal),
+            (String::from("234"), ChunkRole::Digits),
+            (String::from("e14"), ChunkRole::Exponent),
+        ]
+    );
+}
+
+#[test]
+fn golden_display_v1() {
+    let golden = include_str!("golden/display_v1.txt");
+    let mut checked = 0;
+    for line in golden.lines().filter(|line| !line.starts_with('#')) {
+        let mut fields = line.split('\t');
+        let (Some(canonical), Some(display), Some(debug), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            unreachable!("invalid golden line: {line:?}")
+        };
+        let value = Approximint::from_canonical_str(canonical).unwrap();
+        assert_eq!(value.to_string(), display);
+        assert_eq!(value.display_v1().to_string(), display);
+        assert_eq!(format!("{value:?}"), debug);
+        assert_eq!(value.debug_v1().to_string(), debug);
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn locale_round_trips() {
+    type Preset = fn(Approximint) -> WordFormatter<'static>;
+    type Parser = fn(&str) -> Result<Approximint, ParseError<'_>>;
+    let presets: [(Preset, Parser); 4] = [
+        (WordFormatter::english, Approximint::parse_english),
+        (WordFormatter::english_short, Approximint::parse_english),
+        (WordFormatter::german_short, Approximint::parse_german_short),
+        (WordFormatter::japanese, Approximint::parse_japanese),
+    ];
+    for value in [
+        Approximint::new(-999_999),
+        Approximint::new(12_340_000),
+        Approximint::new(-250_000_000).with_added_ten_power(10),
+        Approximint::new(100_000_000).with_added_ten_power(100),
+    ] {
+        for (format, parse) in presets {
+            // Formatting discards digits, so the parsed value must format
+            // identically rather than equal the original value.
+            let formatted = format(value).to_string();
+            let parsed = parse(&formatted).unwrap();
+            assert_eq!(format(parsed).to_string(), formatted);
+        }
+    }
+    assert_eq!(
+        WordFormatter::japanese(Approximint::one_e(52)).to_string(),
+        "1万極"
+    );
+    assert_eq!(
+        Approximint::parse_german_short("1,5 Mio").unwrap_err().kind,
+        ParseErrorKind::UnknownWord
+    );
+}
+
+#[test]
+fn byte_encoding() {
+    for value in [
+        Approximint::ZERO,
+        Approximint::new(-123_456),
+        Approximint::new(987_654_321).with_added_ten_power(42),
+        Approximint::MAX,
+        Approximint::MIN,
+    ] {
+        assert_eq!(Approximint::from_le_bytes(value.to_le_bytes()), value);
+        assert_eq!(Approximint::from_be_bytes(value.to_be_bytes()), value);
+    }
+    assert_eq!(
+        Approximint::new(-2).with_added_ten_power(1).to_be_bytes(),
+        [0xFF, 0xFF, 0xFF, 0xEC, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        (Approximint::new(100_000_000) * Approximint::one_e(2)).to_le_bytes(),
+        [0x00, 0xE1, 0xF5, 0x05, 2, 0, 0, 0]
+    );
+    // Out of range coefficients are normalized.
+    let mut bytes = [0; 8];
+    bytes[..4].copy_from_slice(&i32::MAX.to_le_bytes());
+    assert_eq!(
+        Approximint::from_le_bytes(bytes),
+        Approximint::new(214_748_364).with_added_ten_power(1)
+    );
+}
+
+#[test]
+#[cfg(feature = "strict-debug")]
+#[should_panic = "precision lost: 1e100 + 1 discards an operand"]
+fn strict_debug_add() {
+    let _ = Approximint::one_e(100) + Approximint::ONE;
+}
+
+#[test]
+#[cfg(feature = "strict-debug")]
+#[should_panic = "precision lost: approximating 1234567891 drops digits"]
+fn strict_debug_conversion() {
+    let _ = Approximint::approximate(1_234_567_891_u64);
+}
+
+#[test]
+fn hash() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    assert!(set.insert(Approximint::new(1_000_000_000)));
+    assert!(!set.insert(Approximint::new(100_000_000) * 10));
+    assert!(set.insert(Approximint::ZERO));
+    assert!(!set.insert(Approximint::one_e(100) - Approximint::one_e(100)));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn value_ordering() {
+    let mut values = [
+        Approximint::one_e(100),
+        Approximint::new(-5),
+        Approximint::MAX,
+        Approximint::new(-1_000_000_000),
+        Approximint::ZERO,
+        Approximint::MIN,
+        Approximint::new(999_999_999),
+        Approximint::new(5),
+    ];
+    values.sort();
+    assert_eq!(
+        values,
+        [
+            Approximint::MIN,
+            Approximint::new(-1_000_000_000),
+            Approximint::new(-5),
+            Approximint::ZERO,
+            Approximint::new(5),
+            Approximint::new(999_999_999),
+            Approximint::one_e(100),
+            Approximint::MAX,
+        ]
+    );
+    assert!(Approximint::new(-1_000_000_000) < Approximint::new(-5));
+    assert!(Approxfixed::<2>::from_parts(-10, 0) < Approxfixed::from_parts(-1, 50));
+}
+
+#[test]
+fn integer_comparisons() {
+    let billion = Approximint::new(1_000_000_000);
+    assert!(billion == 1_000_000_000_u64);
+    assert!(billion != 1_000_000_001_u64);
+    assert!(billion < 1_000_000_001_u64);
+    assert!(billion >= 1_000_u64);
+    assert!(billion > -1_i32);
+    assert!(Approximint::new(-5) < 0_u8);
+    assert!(Approximint::new(-5) == -5_i64);
+    assert!(Approximint::one_e(20) > u64::MAX);
+    assert!(-Approximint::one_e(20) < i64::MIN);
+    assert!(Approximint::one_e(30) > u64::MAX);
+    assert!(-Approximint::one_e(30) < i64::MIN);
+    assert!(Approximint::new(7) == 7_u32);
+}
+
+#[test]
+fn f64_comparisons() {
+    let balance = Approximint::new(15) * Approximint::one_e(11);
+    assert!(balance >= 1.5e12);
+    assert!(balance == 1.5e12);
+    assert!(balance < 1.500_000_000_001e12);
+    assert!(balance > 1.499_999_999_999e12);
+    assert!(Approximint::new(-3) < -2.5);
+    assert!(Approximint::new(-3) > -3.5);
+    assert!(Approximint::new(2) > 1.999_999_999);
+    assert!(Approximint::ZERO == -0.0);
+    assert!(Approximint::ZERO < f64::MIN_POSITIVE);
+    assert!(Approximint::ONE > f64::MIN_POSITIVE);
+
+    // Values beyond the range of f64 still compare correctly.
+    let huge = Approximint::new(1) * Approximint::one_e(400);
+    assert!(huge > f64::MAX);
+    assert!(-huge < f64::MIN);
+    assert!(huge < f64::INFINITY);
+    assert!(Approximint::MAX < f64::INFINITY);
+    assert!(Approximint::MIN > f64::NEG_INFINITY);
+
+    // Exact comparisons near the top of f64's range.
+    let max = Approximint::new(179_769_313) * Approximint::one_e(300);
+    assert!(max < f64::MAX);
+    assert!(max > 1.797_693_12e308);
+    assert!(Approximint::new(1) * Approximint::one_e(22) == 1e22);
+    assert!(Approximint::new(1) * Approximint::one_e(23) != 1e23);
+
+    // NaN is unordered.
+    assert!(!Approximint::ONE.eq(&f64::NAN));
+    assert_eq!(Approximint::ONE.partial_cmp(&f64::NAN), None);
+    assert!(!Approximint::ONE.lt(&f64::NAN));
+    assert!(!Approximint::ONE.ge(&f64::NAN));
+}
+
+#[test]
+fn try_into_integers() {
+    assert_eq!(
+        u64::try_from(Approximint::new(999_999_999)),
+        Ok(999_999_999)
+    );
+    assert_eq!(i64::try_from(Approximint::new(-5)), Ok(-5));
+    assert_eq!(u128::try_from(Approximint::ZERO), Ok(0));
+    assert_eq!(u8::try_from(Approximint::new(255)), Ok(255));
+    assert_eq!(
+        u8::try_from(Approximint::new(256)),
+        Err(TryFromApproximintError::OutOfRange)
+    );
+    assert_eq!(
+        u128::try_from(Approximint::new(-1)),
+        Err(TryFromApproximintError::OutOfRange)
+    );
+    assert_eq!(i8::try_from(Approximint::new(-128)), Ok(-128));
+
+    // Values stored with a ten power convert when they fit, agreeing with
+    // the integer comparisons.
+    let billion = Approximint::new(1_000_000_000);
+    assert_eq!(u64::try_from(billion), Ok(1_000_000_000));
+    assert_eq!(i32::try_from(-billion), Ok(-1_000_000_000));
+    assert!(billion == 1_000_000_000_u64);
+    let trillion = Approximint::one_e(12);
+    assert_eq!(u64::try_from(trillion), Ok(1_000_000_000_000));
+    assert_eq!(i64::try_from(-trillion), Ok(-1_000_000_000_000));
+    assert!(trillion == 1_000_000_000_000_u64);
+    assert_eq!(
+        u32::try_from(trillion),
+        Err(TryFromApproximintError::OutOfRange)
+    );
+    assert_eq!(
+        u64::try_from(Approximint::new(1) * Approximint::one_e(20)),
+        Err(TryFromApproximintError::OutOfRange)
+    );
+    assert_eq!(
+        u128::try_from(Approximint::new(1) * Approximint::one_e(20)),
+        Ok(100_000_000_000_000_000_000)
+    );
+    assert_eq!(
+        i128::try_from(Approximint::MIN),
+        Err(TryFromApproximintError::OutOfRange)
+    );
+}
+
+#[test]
+#[expect(clippy::float_cmp)]
+fn to_f64_lossy() {
+    assert_eq!(Approximint::ZERO.to_f64_lossy(), 0.);
+    assert_eq!(f64::from(Approximint::new(999_999_999)), 999_999_999.);
+    assert_eq!(
+        f64::from(Approximint::new(-7) * Approximint::one_e(22)),
+        -7e22
+    );
+    let large = Approximint::new(123_456_789) * Approximint::one_e(100);
+    assert!((large.to_f64_lossy() / 1.234_567_89e108 - 1.).abs() < 1e-12);
+    assert_eq!(
+        (Approximint::new(1) * Approximint::one_e(309)).to_f64_lossy(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        (Approximint::new(-1) * Approximint::one_e(400)).to_f64_lossy(),
+        f64::NEG_INFINITY
+    );
+}
+
+#[test]
+fn try_from_float() {
+    assert_eq!(Approximint::try_from(-123.4), Ok(Approximint::new(-123)));
+    assert_eq!(
+        Approximint::try_from(-1.5e12),
+        Ok(Approximint::new(-15) * Approximint::one_e(11))
+    );
+    assert_eq!(Approximint::try_from(0.0_f32), Ok(Approximint::ZERO));
+    assert_eq!(
+        Approximint::try_from(f64::NAN),
+        Err(TryFromFloatError::NotANumber)
+    );
+    assert_eq!(
+        Approximint::try_from(f32::NEG_INFINITY),
+        Err(TryFromFloatError::Infinite)
+    );
+    assert_eq!(
+        f64::MIN.try_approximate(),
+        Ok(-Approximint::approximate(f64::MAX))
+    );
+
+    // Approximating negative values matches approximating their magnitude.
+    assert_eq!(
+        Approximint::approximate(-1.5e12),
+        -Approximint::approximate(1.5e12)
+    );
+    assert_eq!(Approximint::approximate(f64::NAN), Approximint::ZERO);
+    assert_eq!(Approximint::approximate(f64::INFINITY), Approximint::MAX);
+    assert_eq!(
+        Approximint::approximate(f64::NEG_INFINITY),
+        Approximint::MIN
+    );
+}
+
+#[test]
+fn mul_ratio() {
+    assert_eq!(Approximint::new(10).mul_ratio(1, 4), Approximint::new(3));
+    assert_eq!(Approximint::new(-10).mul_ratio(1, 4), Approximint::new(-3));
+    assert_eq!(Approximint::new(-1).mul_ratio(1, 3), Approximint::ZERO);
+    assert_eq!(Approximint::new(7).mul_ratio(0, 3), Approximint::ZERO);
+    assert_eq!(
+        Approximint::new(999_999_999).mul_ratio(u64::MAX, 1),
+        Approximint::new(184_467_441) * Approximint::one_e(20)
+    );
+
+    // Rounding considers the digits moved out of the ten power.
+    let value = Approximint::new(123_456_789) * Approximint::one_e(20);
+    assert_eq!(
+        value.mul_ratio(1, 4),
+        Approximint::new(308_641_973) * Approximint::one_e(19)
+    );
+    assert_eq!(
+        Approximint::new(999_999_999).mul_ratio(1_000_000_001, 1_000_000_000),
+        Approximint::new(1_000_000_000)
+    );
+    assert_eq!(
+        Approximint::new(999_999_999).mul_ratio(999_999_999, 1_000_000_000),
+        Approximint::new(999_999_998)
+    );
+
+    // Results are computed exactly from the ten power.
+    let large = Approximint::new(2) * Approximint::one_e(30);
+    assert_eq!(
+        large.mul_ratio(1, 3),
+        Approximint::new(666_666_667) * Approximint::one_e(21)
+    );
+    assert_eq!(Approximint::MAX.mul_ratio(10, 1), Approximint::MAX);
+    assert_eq!(Approximint::MIN.mul_ratio(10, 1), Approximint::MIN);
+    assert_eq!(
+        Approximint::MAX.mul_ratio(1, 2).mul_ratio(2, 1),
+        Approximint::MAX.mul_ratio(1, 1)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn float_operators() {
+    let income = Approximint::new(1_200);
+    assert_eq!(income * 0.5_f32, Approximint::new(600));
+    let mut scaled = income;
+    scaled *= 0.25_f32;
+    assert_eq!(scaled, Approximint::new(300));
+
+    let mut health = Approximint::new(1) * Approximint::one_e(20);
+    health /= 2.;
+    assert_eq!(health, Approximint::new(5) * Approximint::one_e(19));
+    assert_eq!(Approximint::new(9) / 3., Approximint::new(3));
+    assert_eq!(Approximint::new(-10) / 4., Approximint::new(-3));
+    assert_eq!(Approximint::new(10) / 0.5, Approximint::new(20));
+    assert_eq!(Approximint::new(10) / 0., Approximint::MAX);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn rounding_modes() {
+    let rounded = |negative, rounding| {
+        let mut info = ScientificInfo::<9>::from_parts(negative, 123_450_001, 0);
+        info.round(3, rounding);
+        info.digits.iter().take(3).collect::<std::vec::Vec<_>>()
+    };
+    assert_eq!(rounded(false, Rounding::HalfUp), b"123");
+    assert_eq!(rounded(false, Rounding::Floor), b"123");
+    assert_eq!(rounded(false, Rounding::Ceil), b"124");
+    assert_eq!(rounded(false, Rounding::TowardZero), b"123");
+    assert_eq!(rounded(true, Rounding::Floor), b"124");
+    assert_eq!(rounded(true, Rounding::Ceil), b"123");
+    assert_eq!(rounded(true, Rounding::TowardZero), b"123");
+
+    let value = Approximint::new(999_999_999);
+    assert_eq!(
+        value.mul_f64_with(2.5, Rounding::HalfUp),
+        Approximint::new(250_000_000) * 10
+    );
+    let value = Approximint::new(5);
+    assert_eq!(
+        value.mul_f64_with(0.5, Rounding::HalfUp),
+        Approximint::new(3)
+    );
+    assert_eq!(
+        value.mul_f64_with(0.5, Rounding::HalfDown),
+        Approximint::new(2)
+    );
+    assert_eq!(
+        value.mul_f64_with(-0.5, Rounding::HalfDown),
+        Approximint::new(-2)
+    );
+    assert_eq!(
+        value.mul_f64_with(0.5, Rounding::Floor),
+        Approximint::new(2)
+    );
+    assert_eq!(value.mul_f64_with(0.5, Rounding::Ceil), Approximint::new(3));
+    assert_eq!(
+        value.mul_f64_with(-0.5, Rounding::Ceil),
+        Approximint::new(-2)
+    );
+    assert_eq!(
+        value.mul_f64_with(-0.5, Rounding::TowardZero),
+        Approximint::new(-2)
+    );
+
+    // Rounding applies to the digits beyond the 9 kept digits.
+    let billion = Approximint::new(1_000_000_000);
+    assert_eq!(
+        billion.mul_f64_with(1.000_000_001_9, Rounding::Floor),
+        Approximint::new(1_000_000_000)
+    );
+    assert_eq!(
+        billion.mul_f64_with(1.000_000_001_9, Rounding::Ceil),
+        Approximint::new(1_000_000_010)
+    );
+    assert_eq!(
+        value.mul_f64_with(f64::NEG_INFINITY, Rounding::Floor),
+        Approximint::MIN
+    );
+    assert_eq!(
+        value.mul_f64_with(f64::NAN, Rounding::Floor),
+        Approximint::ZERO
+    );
+}
+
+#[test]
+fn random_log_uniform() {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut random = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let low = Approximint::new(5);
+    let high = Approximint::new(12) * Approximint::one_e(20);
+    let mut exponents = [0; 22];
+    for _ in 0..2_200 {
+        let value = Approximint::random_log_uniform(&mut random, low, high);
+        assert!(value >= low && value <= high, "{value:?}");
+        let exponent = usize::try_from(value.integer_digits() - 1).unwrap();
+        exponents[exponent] += 1;
+    }
+    // Every exponent is chosen roughly equally often.
+    assert!(
+        exponents.iter().all(|count| (50..150).contains(count)),
+        "{exponents:?}"
+    );
+
+    // Single values and single decades are supported.
+    assert_eq!(
+        Approximint::random_log_uniform(&mut random, high, high),
+        high
+    );
+    let value =
+        Approximint::random_log_uniform(&mut random, Approximint::new(3), Approximint::new(4));
+    assert!(value == 3 || value == 4);
+    let huge = Approximint::MAX;
+    assert_eq!(
+        Approximint::random_log_uniform(&mut random, huge, huge),
+        huge
+    );
+}
+
+#[test]
+fn padding() {
+    let value = Approximint::new(1_234_567);
+    assert_eq!(std::format!("[{value:>12}]"), "[   1,234,567]");
+    assert_eq!(std::format!("[{value:12}]"), "[   1,234,567]");
+    assert_eq!(std::format!("[{value:<12}]"), "[1,234,567   ]");
+    assert_eq!(std::format!("[{value:*^14}]"), "[**1,234,567***]");
+    assert_eq!(std::format!("[{value:3}]"), "[1,234,567]");
+    assert_eq!(
+        std::format!(
+            "[{:>8}]",
+            ScientificFormatter::from(Approximint::new(-1_234_567))
+        ),
+        "[-1.234e6]"
+    );
+    assert_eq!(
+        std::format!(
+            "[{:>10}]",
+            ScientificFormatter::from(Approximint::new(-1_234_567))
+        ),
+        "[  -1.234e6]"
+    );
+    assert_eq!(
+        std::format!("[{:<14}]", DecimalFormatter::from(Approximint::new(-1_000))),
+        "[-1,000        ]"
+    );
+    let words = WordFormatter::english(Approximint::new(1_500_000_000));
+    assert_eq!(std::format!("[{words:>14}]"), "[   1.5 billion]");
+    assert_eq!(
+        std::format!("[{:^7}]", Approximint::new(12).as_canonical()),
+        "[ 12e0  ]"
+    );
+    assert_eq!(
+        std::format!("[{:>6}]", Approxfixed::<2>::from_parts(1, 5)),
+        "[  1.05]"
+    );
+    // Multi-byte fill characters and words are measured in characters.
+    let value = Approximint::new(12) * Approximint::one_e(4);
+    let japanese = WordFormatter::japanese(value);
+    assert_eq!(std::format!("[{japanese:・>5}]"), "[・・12万]");
+}
+
+#[test]
+fn precision() {
+    let value = Approximint::new(123_456_789) * Approximint::one_e(4);
+    assert_eq!(std::format!("{value:.3}"), "1.23e12");
+    assert_eq!(std::format!("{value:.1}"), "1e12");
+    assert_eq!(std::format!("{value:.20}"), "1.23456789e12");
+    assert_eq!(std::format!("[{value:>10.2}]"), "[    1.2e12]");
+    assert_eq!(
+        std::format!("{:.2}", ScientificFormatter::from(value).rounded()),
+        "1.2e12"
+    );
+    assert_eq!(
+        std::format!("{:.6}", ScientificFormatter::from(value)),
+        "1.23456e12"
+    );
+    assert_eq!(
+        std::format!("{:.3}", WordFormatter::english(value)),
+        "1.234 trillion"
+    );
+    assert_eq!(
+        std::format!("{:.0}", WordFormatter::english(value)),
+        "1 trillion"
+    );
+    assert_eq!(
+        std::format!("{:.2}", value.display().words()),
+        "1.23 trillion"
+    );
+    // Decimal notation has no fractional digits to limit.
+    assert_eq!(std::format!("{:.2}", Approximint::new(1_234)), "1,234");
+}
+
+#[test]
+fn plus_sign_flag() {
+    let gain = Approximint::new(1_200_000_000);
+    assert_eq!(std::format!("{gain:+}"), "+1.200e9");
+    assert_eq!(std::format!("{:+}", -gain), "-1.200e9");
+    assert_eq!(std::format!("{:+}", Approximint::ZERO), "0");
+    assert_eq!(std::format!("[{:>+8}]", Approximint::new(42)), "[     +42]");
+    assert_eq!(std::format!("{gain:+.1}"), "+1e9");
+
+    let words = WordFormatter::english(gain).plus_sign();
+    assert_eq!(words.to_string(), "+1.2 billion");
+    assert_eq!(
+        std::format!("{:+}", WordFormatter::english(gain)),
+        "+1.2 billion"
+    );
+    assert_eq!(
+        WordFormatter::english(-gain).plus_sign().to_string(),
+        "-1.2 billion"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::new(12))
+            .plus_sign()
+            .to_string(),
+        "+12"
+    );
+    assert_eq!(
+        DecimalFormatter::from(Approximint::new(1_234))
+            .plus_sign()
+            .to_string(),
+        "+1,234"
+    );
+    assert_eq!(
+        ScientificFormatter::from(gain).plus_sign().to_string(),
+        "+1.200e9"
+    );
+    assert_eq!(
+        std::format!("{:+}", ScientificFormatter::from(-gain)),
+        "-1.200e9"
+    );
+    assert_eq!(std::format!("{:+}", gain.display().words()), "+1.2 billion");
+    assert_eq!(
+        std::format!("{:+}", Approxfixed::<2>::from_parts(1, 5)),
+        "+1.05"
+    );
+}
+
+#[test]
+fn negative_affixes() {
+    let options = FormatOptions::new().accounting();
+    assert_eq!(
+        options.format(Approximint::new(-1_234)).to_string(),
+        "(1,234)"
+    );
+    assert_eq!(options.format(Approximint::new(1_234)).to_string(), "1,234");
+    assert_eq!(options.format(Approximint::ZERO).to_string(), "0");
+    assert_eq!(
+        options.format(Approximint::new(-1_234_567_890)).to_string(),
+        "(1.234e9)"
+    );
+    assert_eq!(
+        Approximint::new(-1_500_000_000)
+            .display()
+            .words()
+            .accounting()
+            .to_string(),
+        "(1.5 billion)"
+    );
+    assert_eq!(
+        std::format!("[{:>9}]", options.format(Approximint::new(-5))),
+        "[      (5)]"
+    );
+    assert_eq!(
+        Approximint::new(-12)
+            .display()
+            .negative_affixes("\u{2212}", "")
+            .to_string(),
+        "\u{2212}12"
+    );
+    assert_eq!(
+        Approxfixed::<2>::from_parts(-1, 5)
+            .format(FormatOptions::new().accounting())
+            .to_string(),
+        "(1.05)"
+    );
+}
+
+#[test]
+fn suffix_formatter() {
+    let suffixed = |value: Approximint| value.as_suffixed().to_string();
+    assert_eq!(suffixed(Approximint::new(-999)), "-999");
+    assert_eq!(suffixed(Approximint::new(1_500)), "1.5K");
+    assert_eq!(
+        suffixed(Approximint::new(45_600) * Approximint::one_e(12)),
+        "45.6Qa"
+    );
+    assert_eq!(
+        suffixed(Approximint::new(999_994) * Approximint::one_e(30)),
+        "999.99Dc"
+    );
+    assert_eq!(
+        suffixed(Approximint::new(1) * Approximint::one_e(36)),
+        "1e36"
+    );
+    assert_eq!(
+        suffixed(-Approximint::new(123_456) * Approximint::one_e(40)),
+        "-1.23e45"
+    );
+    assert_eq!(
+        std::format!("[{:>7}]", Approximint::new(1_500).as_suffixed()),
+        "[   1.5K]"
+    );
+
+    let custom = SuffixFormatter::new(Approximint::new(2_500_000))
+        .suffixes(&[(3, "k"), (6, "m")])
+        .decimal_places(1);
+    assert_eq!(custom.to_string(), "2.5m");
+    let beyond = SuffixFormatter::new(Approximint::new(25) * Approximint::one_e(8))
+        .suffixes(&[(3, "k"), (6, "m")])
+        .scientific(ScientificSettings::new().significant_digits(2));
+    assert_eq!(beyond.to_string(), "2.5e9");
+}
+
+#[test]
+fn letter_formatter() {
+    let lettered = |num: Approximint| LetterFormatter::new(num).to_string();
+    assert_eq!(lettered(Approximint::ZERO), "0");
+    assert_eq!(lettered(Approximint::new(-999)), "-999");
+    assert_eq!(lettered(Approximint::new(1_000)), "1a");
+    assert_eq!(lettered(Approximint::new(12_345)), "12.34a");
+    assert_eq!(
+        lettered(Approximint::new(5) * Approximint::one_e(10)),
+        "50c"
+    );
+    assert_eq!(lettered(Approximint::one_e(78)), "1z");
+    assert_eq!(lettered(Approximint::one_e(81)), "1aa");
+    assert_eq!(lettered(Approximint::one_e(156)), "1az");
+    assert_eq!(lettered(Approximint::one_e(159)), "1ba");
+    assert_eq!(lettered(Approximint::one_e(2_106)), "1zz");
+    assert_eq!(lettered(Approximint::one_e(2_109)), "1aaa");
+    assert_eq!(lettered(-Approximint::new(999_996)), "-1b");
+    assert_eq!(
+        lettered(Approximint::new(123) * Approximint::one_e(u32::MAX - 2)),
+        "1.23dplwcyg"
+    );
+    assert_eq!(
+        LetterFormatter::new(Approximint::new(1_234_567))
+            .start(6)
+            .to_string(),
+        "1.23a"
+    );
+    assert_eq!(
+        LetterFormatter::new(Approximint::new(1_234_567))
+            .decimal_places(0)
+            .to_string(),
+        "1b"
+    );
+    assert_eq!(
+        format!("{:+.1}", LetterFormatter::new(Approximint::new(1_250))),
+        "+1.2a"
+    );
+    assert_eq!(
+        format!("[{:>6}]", LetterFormatter::new(Approximint::new(1_500))),
+        "[  1.5a]"
+    );
+}
+
+#[test]
+fn smart_formatter() {
+    let smart = |num: Approximint| SmartFormatter::new(num).to_string();
+    assert_eq!(smart(Approximint::ZERO), "0");
+    assert_eq!(smart(Approximint::new(-999_999)), "-999,999");
+    assert_eq!(smart(Approximint::new(1_000_000)), "1M");
+    assert_eq!(
+        smart(Approximint::new(456) * Approximint::one_e(31)),
+        "4.56Dc"
+    );
+    assert_eq!(smart(Approximint::one_e(36)), "1e36");
+
+    let custom = |num: Approximint| {
+        SmartFormatter::new(num)
+            .words_at(3)
+            .scientific_at(9)
+            .decimal_places(1)
+            .to_string()
+    };
+    assert_eq!(custom(Approximint::new(999)), "999");
+    assert_eq!(custom(Approximint::new(1_250)), "1.2K");
+    assert_eq!(custom(Approximint::new(999_949_999)), "999.9M");
+    assert_eq!(custom(Approximint::new(1_000_000_000)), "1e9");
+    assert_eq!(
+        format!("[{:>6}]", SmartFormatter::new(Approximint::new(1_500_000))),
+        "[  1.5M]"
+    );
+}
+
+#[test]
+fn exponent_marker() {
+    let num = Approximint::new(-1_234_567);
+    assert_eq!(
+        num.as_scientific().exponent_marker("E").to_string(),
+        "-1.234E6"
+    );
+    assert_eq!(
+        num.as_scientific()
+            .exponent_marker(" × 10^")
+            .truncate_zeroes()
+            .significant_digits(1)
+            .to_string(),
+        "-1 × 10^6"
+    );
+    assert_eq!(
+        format!("{:>9}", num.as_scientific().exponent_marker("E")),
+        " -1.234E6"
+    );
+}
+
+#[test]
+fn exponent_separator() {
+    let grouped = |num: Approximint| num.as_scientific().exponent_separator('_').to_string();
+    assert_eq!(grouped(Approximint::new(1_234)), "1.234e3");
+    assert_eq!(
+        grouped(Approximint::new(-12) * Approximint::one_e(999)),
+        "-1.200e1_000"
+    );
+    assert_eq!(grouped(Approximint::one_e(123_456)), "1.000e123_456");
+    assert_eq!(
+        grouped(Approximint::new(9_999) * Approximint::one_e(u32::MAX)),
+        "9.999e4_294_967_298"
+    );
+}
+
+#[test]
+fn scientific_decimal_below() {
+    let formatted = |num: Approximint| num.as_scientific().decimal_below(45).to_string();
+    assert_eq!(formatted(Approximint::new(-1_234)), "-1,234");
+    assert_eq!(
+        formatted(Approximint::new(12) * Approximint::one_e(40)),
+        "120,000,000,000,000,000,000,000,000,000,000,000,000,000"
+    );
+    assert_eq!(formatted(Approximint::one_e(45)), "1.000e45");
+    assert_eq!(
+        format!(
+            "{:+}",
+            Approximint::new(1_234).as_scientific().decimal_below(6)
+        ),
+        "+1,234"
+    );
+}
+
+#[test]
+fn decimal_scientific_after() {
+    let num = Approximint::new(123_456_789) * Approximint::one_e(37);
+    assert_eq!(num.as_decimal().to_string(), "1.234e45");
+    assert_eq!(
+        num.as_decimal().scientific_after(40).to_string(),
+        "1,234,567,890,000,000,000,000,000,000,000,000,000,000,000,000"
+    );
+    assert_eq!(
+        num.as_decimal().never_scientific().to_string(),
+        "1,234,567,890,000,000,000,000,000,000,000,000,000,000,000,000"
+    );
+    assert_eq!(
+        Approximint::new(-5)
+            .as_decimal()
+            .scientific_after(0)
+            .to_string(),
+        "-5e0"
+    );
+}
+
+#[test]
+fn decimal_word_fallback() {
+    let formatted = |num: Approximint| {
+        num.as_decimal()
+            .scientific_after(6)
+            .word_fallback(&[(3, "K"), (6, "M"), (9, "B"), (12, "T")])
+            .separator('_')
+            .to_string()
+    };
+    assert_eq!(formatted(Approximint::new(-999_999_999)), "-999_999_999");
+    assert_eq!(
+        formatted(Approximint::new(-123_456_789) * Approximint::one_e(6)),
+        "-123.4 T"
+    );
+    assert_eq!(
+        format!(
+            "{:+}",
+            Approximint::one_e(40)
+                .as_decimal()
+                .word_fallback(&[(3, "thousand"), (39, "duodecillion")])
+        ),
+        "+10 duodecillion"
+    );
+}
+
+#[test]
+fn group_sizes() {
+    let grouped =
+        |num: Approximint, sizes: &'static [u8]| num.as_decimal().group_sizes(sizes).to_string();
+    let num = Approximint::new(-123_456_789) * Approximint::one_e(3);
+    assert_eq!(grouped(num, &[3, 2]), "-1,23,45,67,89,000");
+    assert_eq!(grouped(num, &[4]), "-1234,5678,9000");
+    assert_eq!(grouped(num, &[1, 2, 3]), "-123,456,789,00,0");
+    assert_eq!(grouped(num, &[2, 0]), "-1234567890,00");
+    assert_eq!(grouped(num, &[]), "-123456789000");
+    assert_eq!(grouped(Approximint::new(999), &[3, 2]), "999");
+    assert_eq!(grouped(Approximint::new(1_000), &[3, 2]), "1,000");
+    assert_eq!(
+        num.as_decimal()
+            .group_sizes(&[3, 2])
+            .digits_per_separator(4)
+            .to_string(),
+        "-1234,5678,9000"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::new(123_456_789))
+            .group_sizes(&[3, 2])
+            .to_string(),
+        "12,34,56,789"
+    );
+}
+
+#[test]
+fn locales() {
+    let num = Approximint::new(-123_456_789) * Approximint::one_e(3);
+    let decimal = |locale: Locale| num.as_decimal().locale(locale).to_string();
+    assert_eq!(decimal(Locale::new()), "-123,456,789,000");
+    assert_eq!(decimal(Locale::en_us()), "-123,456,789,000");
+    assert_eq!(decimal(Locale::de_de()), "-123.456.789.000");
+    assert_eq!(
+        decimal(Locale::fr_fr()),
+        "-123\u{202f}456\u{202f}789\u{202f}000"
+    );
+    assert_eq!(decimal(Locale::hi_in()), "-1,23,45,67,89,000");
+    assert_eq!(decimal(Locale::ja_jp()), "-123,456,789,000");
+    assert_eq!(
+        decimal(Locale::new().separator(' ').group_sizes(&[4])),
+        "-1234 5678 9000"
+    );
+
+    assert_eq!(
+        num.as_scientific().locale(Locale::fr_fr()).to_string(),
+        "-1,234E11"
+    );
+    assert_eq!(
+        WordFormatter::english(num)
+            .locale(Locale::de_de())
+            .to_string(),
+        "-123,4 billion"
+    );
+
+    let options = FormatOptions::new().locale(Locale::hi_in());
+    assert_eq!(
+        options.format(num).decimal_notation().to_string(),
+        "-1,23,45,67,89,000"
+    );
+    assert_eq!(options.format(num).scientific().to_string(), "-1.234E11");
+    assert_eq!(options.format(num).words().to_string(), "-123.4 billion");
+    assert_eq!(
+        options
+            .format(Approximint::new(123_456_789))
+            .words()
+            .to_string(),
+        "12,34,56,789"
+    );
+    assert_eq!(
+        FormatOptions::new()
+            .locale(Locale::de_de())
+            .format(num)
+            .words()
+            .to_string(),
+        "-123,4 billion"
+    );
+}
+
+#[test]
+fn unified_rounding() {
+    let scientific = |num: i32, rounding: Rounding| {
+        Approximint::new(num)
+            .as_scientific()
+            .significant_digits(2)
+            .rounding(rounding)
+            .to_string()
+    };
+    assert_eq!(scientific(125, Rounding::HalfUp), "1.3e2");
+    assert_eq!(scientific(125, Rounding::HalfDown), "1.2e2");
+    assert_eq!(scientific(125, Rounding::HalfEven), "1.2e2");
+    assert_eq!(scientific(135, Rounding::HalfEven), "1.4e2");
+    assert_eq!(scientific(1_251, Rounding::HalfEven), "1.3e3");
+    assert_eq!(scientific(-125, Rounding::HalfEven), "-1.2e2");
+    assert_eq!(scientific(121, Rounding::Ceil), "1.3e2");
+    assert_eq!(scientific(-121, Rounding::Ceil), "-1.2e2");
+    assert_eq!(scientific(-121, Rounding::Floor), "-1.3e2");
+    assert_eq!(scientific(129, Rounding::TowardZero), "1.2e2");
+
+    let num = Approximint::new(123_456_789) * Approximint::one_e(30);
+    assert_eq!(
+        num.as_decimal().rounding(Rounding::Ceil).to_string(),
+        "1.235e38"
+    );
+    assert_eq!(
+        num.as_decimal()
+            .rounding(Rounding::Floor)
+            .word_fallback(&[(3, "thousand"), (36, "undecillion")])
+            .to_string(),
+        "123.4 undecillion"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::new(125) * Approximint::one_e(7))
+            .rounding(Rounding::HalfEven)
+            .to_string(),
+        "1.2 billion"
+    );
+    assert_eq!(
+        FormatOptions::new()
+            .rounding(Rounding::HalfEven)
+            .format(Approximint::new(12_355))
+            .scientific()
+            .to_string(),
+        "1.236e4"
+    );
+    assert_eq!(
+        FormatOptions::new()
+            .rounding(Rounding::Floor)
+            .format(Approximint::new(-129_999_999) * Approximint::new(10))
+            .words()
+            .to_string(),
+        "-1.3 billion"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn unified_rounding_float() {
+    assert_eq!(
+        Approximint::new(5).mul_f64_with(0.5, Rounding::HalfEven),
+        Approximint::new(2)
+    );
+    assert_eq!(
+        Approximint::new(7).mul_f64_with(0.5, Rounding::HalfEven),
+        Approximint::new(4)
+    );
+}
+
+#[test]
+fn word_rounding_carry() {
+    let rounded = |num: Approximint| {
+        WordFormatter::english(num)
+            .decimal_before_10_power(6)
+            .rounded()
+            .to_string()
+    };
+    assert_eq!(rounded(Approximint::new(999_960_000)), "1 billion");
+    assert_eq!(rounded(Approximint::new(-999_960_000)), "-1 billion");
+    assert_eq!(rounded(Approximint::new(999_940_000)), "999.9 million");
+    assert_eq!(rounded(Approximint::new(999_999)), "999,999");
+    assert_eq!(rounded(Approximint::new(9_999_500)), "10 million");
+    assert_eq!(
+        rounded(Approximint::new(999_999_999) * Approximint::one_e(94)),
+        "1,000 googol"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::new(999_960_000))
+            .decimal_before_10_power(6)
+            .rounded()
+            .decimal_places(2)
+            .to_string(),
+        "999.96 million"
+    );
+}
+
+#[test]
+fn word_decimal_places() {
+    let num = Approximint::new(-123_456_789) * Approximint::one_e(3);
+    let formatted = |places: u8| {
+        WordFormatter::english(num)
+            .decimal_places(places)
+            .to_string()
+    };
+    assert_eq!(formatted(0), "-123 billion");
+    assert_eq!(formatted(2), "-123.45 billion");
+    assert_eq!(formatted(6), "-123.456789 billion");
+    assert_eq!(formatted(8), "-123.456789 billion");
+    assert_eq!(
+        WordFormatter::english(num)
+            .decimal_places(0)
+            .rounded()
+            .to_string(),
+        "-123 billion"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::new(12) * Approximint::one_e(8))
+            .decimal_places(3)
+            .to_string(),
+        "1.2 billion"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn borrowed_word_list() {
+    let words: std::vec::Vec<(u32, std::string::String)> = std::vec![
+        (3, std::string::String::from("Tausend")),
+        (6, std::string::String::from("Millionen")),
+    ];
+    let borrowed: std::vec::Vec<(u32, &str)> = words
+        .iter()
+        .map(|(power, word)| (*power, word.as_str()))
+        .collect();
+    let formatted = WordFormatter::new(Approximint::new(-4_500_000), &borrowed)
+        .decimal(',')
+        .separator('.')
+        .to_string();
+    assert_eq!(formatted, "-4,5 Millionen");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn owned_word_list() {
+    let words = std::vec![
+        (3, std::string::String::from("Tausend")),
+        (6, std::string::String::from("Millionen")),
+        (9, std::string::String::from("Milliarden")),
+    ];
+    let formatter = WordFormatter::with_owned_words(Approximint::new(-4_500_000), words)
+        .decimal(',')
+        .separator('.');
+    assert_eq!(formatter.to_string(), "-4,5 Millionen");
+    assert_eq!(formatter.clone().to_string(), "-4,5 Millionen");
+    assert_eq!(
+        std::format!("{:>16.2}", formatter.rounded()),
+        "  -4,5 Millionen"
+    );
+    assert_eq!(
+        WordFormatter::with_owned_words(
+            Approximint::new(123) * Approximint::one_e(10),
+            std::vec![(3, std::string::String::from("k"))]
+        )
+        .word_separator("")
+        .to_string(),
+        "1,230kkk"
+    );
+}
+
+#[test]
+fn myriad_words() {
+    assert_eq!(
+        WordFormatter::chinese(Approximint::new(-9_999)).to_string(),
+        "-9999"
+    );
+    assert_eq!(
+        WordFormatter::chinese(Approximint::new(99_990_000)).to_string(),
+        "9999万"
+    );
+    assert_eq!(
+        WordFormatter::chinese(Approximint::new(123_456_789) * Approximint::one_e(4)).to_string(),
+        "1.2兆"
+    );
+    assert_eq!(
+        WordFormatter::chinese(Approximint::new(123_456_789) * Approximint::one_e(40)).to_string(),
+        "1.2极"
+    );
+    assert_eq!(
+        WordFormatter::chinese(Approximint::new(123_456_789) * Approximint::one_e(52)).to_string(),
+        "1.2兆极"
+    );
+    assert_eq!(
+        WordFormatter::chinese(Approximint::new(123_456_789) * Approximint::one_e(44))
+            .decimal_before_10_power(8)
+            .to_string(),
+        "1,2345.6极"
+    );
+    assert_eq!(
+        WordFormatter::chinese_traditional(Approximint::new(15_000)).to_string(),
+        "1.5萬"
+    );
+    assert_eq!(
+        WordFormatter::korean(Approximint::new(123_456_789)).to_string(),
+        "1.2억"
+    );
+    assert_eq!(
+        WordFormatter::korean(Approximint::new(999_950_000))
+            .rounded()
+            .to_string(),
+        "10억"
+    );
+}
+
+#[test]
+fn unsigned_multiplication() {
+    let cost = Approximint::new(120);
+    // Integer literals infer to i32, including when calling methods on the
+    // product.
+    assert_eq!(cost * 5, Approximint::new(600));
+    assert_eq!((cost * 5).powi(2), Approximint::new(360_000));
+    let mut total = cost;
+    total *= 5;
+    assert_eq!(total, Approximint::new(600));
+
+    assert_eq!(cost.mul_u32(5), Approximint::new(600));
+    assert_eq!(
+        cost.mul_u32(4_000_000_000),
+        Approximint::new(48) * Approximint::one_e(10)
+    );
+    assert_eq!(cost.mul_u64(5), Approximint::new(600));
+    assert_eq!(
+        cost.mul_u64(10_000_000_000_000_000_000),
+        Approximint::new(12) * Approximint::one_e(20)
+    );
+    assert_eq!(Approximint::MAX.mul_u64(u64::MAX), Approximint::MAX);
+    assert_eq!(Approximint::MIN.mul_u32(2), Approximint::MIN);
+    assert_eq!(cost.mul_u64(0), Approximint::ZERO);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn float_division_tiny_divisors() {
+    // 1e-320 is a subnormal f64 whose closest value is 9.99988671826831e-321.
+    assert_eq!(
+        Approximint::new(7) / 1e-320,
+        Approximint::new(700_007_793) * Approximint::one_e(312)
+    );
+    assert_eq!(
+        Approximint::new(7) / 1e-300,
+        Approximint::new(7) * Approximint::one_e(300)
+    );
+    assert_eq!(
+        Approximint::new(-3) / f64::MIN_POSITIVE,
+        Approximint::new(-134_826_985) * Approximint::one_e(300)
+    );
+    assert_eq!(
+        Approximint::new(7) / -5e-324,
+        Approximint::new(-141_681_577) * Approximint::one_e(316)
+    );
+    assert_eq!(Approximint::new(1) / 1e300, Approximint::ZERO);
+    assert_eq!(
+        (Approximint::new(3) * Approximint::one_e(400)) / 1e300,
+        Approximint::new(3) * Approximint::one_e(100)
+    );
+    assert_eq!(Approximint::new(10) / f64::INFINITY, Approximint::ZERO);
+    assert_eq!(Approximint::new(-10) / 0., Approximint::MIN);
+}
+
+#[test]
+fn digits_are_clamped() {
+    let n = Approximint::new(123_456_789) * Approximint::one_e(3);
+    assert_eq!(
+        n.display().scientific().digits(9).to_string(),
+        "1.23456789e11"
+    );
+    assert_eq!(
+        n.display().scientific().digits(u8::MAX).to_string(),
+        "1.23456789e11"
+    );
+    assert_eq!(
+        n.display().scientific().rounded().digits(8).to_string(),
+        "1.2345679e11"
+    );
+    assert_eq!(
+        n.display().scientific().digits(8).rounded().to_string(),
+        "1.2345679e11"
+    );
+    assert_eq!(
+        FormatOptions::new()
+            .notation(Notation::Scientific)
+            .rounded()
+            .digits(9)
+            .format(n)
+            .to_string(),
+        "1.2345679e11"
+    );
+}
+
+#[test]
+fn scientific_decimal_fallback_locale() {
+    let num = Approximint::new(1_234_567);
+    assert_eq!(
+        num.as_scientific()
+            .locale(Locale::de_de())
+            .decimal_below(9)
+            .to_string(),
+        "1.234.567"
+    );
+    assert_eq!(
+        Approximint::new(123_456_789)
+            .as_scientific()
+            .locale(Locale::hi_in())
+            .decimal_below(9)
+            .to_string(),
+        "12,34,56,789"
+    );
+    assert_eq!(
+        num.as_scientific()
+            .locale(Locale::de_de())
+            .plain_below(9)
+            .to_string(),
+        "1234567"
+    );
+    assert_eq!(
+        ScientificFormatter::with_settings(
+            num,
+            ScientificSettings::new()
+                .locale(Locale::fr_fr())
+                .decimal_below(9)
+        )
+        .to_string(),
+        "1\u{202f}234\u{202f}567"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn layout_chunks_locale() {
+    use std::vec::Vec;
+
+    use crate::ChunkRole;
+
+    let value = Approximint::new(123_456_789).with_added_ten_power(6);
+    let options = FormatOptions::new().locale(Locale::de_de());
+    let chunks: Vec<_> = value
+        .layout_chunks(&options.notation(Notation::English))
+        .collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("123"), ChunkRole::Digits),
+            (String::from(","), ChunkRole::Decimal),
+            (String::from("4"), ChunkRole::Digits),
+            (String::from(" "), ChunkRole::Space),
+            (String::from("trillion"), ChunkRole::Word),
+        ]
+    );
+
+    let chunks: Vec<_> = value.layout_chunks(&options).collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("1"), ChunkRole::Digits),
+            (String::from(","), ChunkRole::Decimal),
+            (String::from("234"), ChunkRole::Digits),
+            (String::from("E14"), ChunkRole::Exponent),
+        ]
+    );
+
+    let options = FormatOptions::new().locale(Locale::new().exponent_marker(" × 10^"));
+    let chunks: Vec<_> = value.layout_chunks(&options).collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("1"), ChunkRole::Digits),
+            (String::from("."), ChunkRole::Decimal),
+            (String::from("234"), ChunkRole::Digits),
+            (String::from(" × 10^14"), ChunkRole::Exponent),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "test-util")]
+fn roundtrip_helpers() {
+    use crate::test_util::{assert_roundtrip, RoundtripNotation};
+
+    const WORDS: &[(u32, &str)] = &[(3, "thousand"), (6, "million"), (100, "googol")];
+    const SUFFIXES: &[(u32, &str)] = &[(3, "k"), (6, "m")];
+
+    for value in [
+        Approximint::ZERO,
+        Approximint::new(-42),
+        Approximint::new(1_234_567),
+        Approximint::new(999_999_999) * Approximint::one_e(7),
+        Approximint::new(-123_456_789) * Approximint::one_e(150),
+        Approximint::new(5) * Approximint::one_e(1_000),
+    ] {
+        assert_roundtrip(value, RoundtripNotation::Canonical);
+        assert_roundtrip(value, RoundtripNotation::Display);
+        assert_roundtrip(value, RoundtripNotation::English);
+        assert_roundtrip(value, RoundtripNotation::Words(WORDS));
+        assert_roundtrip(value, RoundtripNotation::Suffixed(SUFFIXES));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-util")]
+#[should_panic = "parsed as 1"]
+fn roundtrip_helpers_empty_word() {
+    use crate::test_util::{assert_roundtrip, RoundtripNotation};
+
+    assert_roundtrip(
+        Approximint::new(1_234_567),
+        RoundtripNotation::Words(&[(6, "")]),
+    );
+}
+
+#[test]
+fn decimal_word_fallback_group_sizes() {
+    let num = Approximint::new(123_456_789);
+    let words = &[(9, "billion")];
+    assert_eq!(
+        num.as_decimal()
+            .scientific_after(0)
+            .word_fallback(words)
+            .group_sizes(&[3, 2])
+            .to_string(),
+        "12,34,56,789"
+    );
+    assert_eq!(
+        num.as_decimal()
+            .scientific_after(0)
+            .word_fallback(words)
+            .locale(Locale::hi_in())
+            .to_string(),
+        "12,34,56,789"
+    );
+}
+
+#[test]
+fn scientific_plain_below_large_threshold() {
+    let num = Approximint::new(123_456_789) * Approximint::one_e(31);
+    assert_eq!(
+        num.as_scientific().plain_below(40).to_string(),
+        "1234567890000000000000000000000000000000"
+    );
+    assert_eq!(num.as_scientific().plain_below(39).to_string(), "1.234e39");
+}
+
+#[test]
+fn word_fraction_digits() {
+    let num = Approximint::new(987_654_321) * Approximint::one_e(6);
+    let formatted = |digits: u8| {
+        WordFormatter::english(num)
+            .fraction_digits(digits)
+            .word_separator("")
+            .to_string()
+    };
+    assert_eq!(formatted(0), "987trillion");
+    assert_eq!(formatted(8), "987.654321trillion");
+    assert_eq!(formatted(9), formatted(8));
+    assert_eq!(formatted(u8::MAX), formatted(8));
+
+    let small = Approximint::new(1) * Approximint::one_e(6);
+    assert_eq!(
+        WordFormatter::english(small - Approximint::ONE)
+            .decimal_before_10_power(3)
+            .fraction_digits(8)
+            .to_string(),
+        "999.999 thousand"
+    );
+    assert_eq!(
+        WordFormatter::english(Approximint::new(123_456_789) * 10)
+            .fraction_digits(8)
+            .to_string(),
+        "1.23456789 billion"
+    );
+}
+
+#[test]
+fn english_compact_residual_exponent_marker() {
+    assert_eq!(
+        WordFormatter::new(Approximint::one_e(100) * 123_456_789, &[(100, "googol")])
+            .compact_residual()
+            .exponent_marker("E")
+            .to_string(),
+        "1.234E8 googol"
+    );
+    assert_eq!(
+        WordFormatter::new(Approximint::one_e(100) * 123_456_789, &[(100, "googol")])
+            .compact_residual()
+            .locale(Locale::de_de())
+            .to_string(),
+        "1,234E8 googol"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn layout_chunks_affixes() {
+    use std::vec::Vec;
+
+    use crate::ChunkRole;
+
+    let options = FormatOptions::new().negative_affixes("(", ")");
+    let chunks: Vec<_> = Approximint::new(-1_234).layout_chunks(&options).collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("("), ChunkRole::Sign),
+            (String::from("1"), ChunkRole::Digits),
+            (String::from(","), ChunkRole::Separator),
+            (String::from("234"), ChunkRole::Digits),
+            (String::from(")"), ChunkRole::Sign),
+        ]
+    );
+
+    let mut options = FormatOptions::new().notation(Notation::Scientific);
+    options.scientific = options.scientific.approx_marker("~");
+    let chunks: Vec<_> = (-Approximint::one_e(42)).layout_chunks(&options).collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("~"), ChunkRole::Approximate),
+            (String::from("-"), ChunkRole::Sign),
+            (String::from("1"), ChunkRole::Digits),
+            (String::from("."), ChunkRole::Decimal),
+            (String::from("000"), ChunkRole::Digits),
+            (String::from("e42"), ChunkRole::Exponent),
+        ]
+    );
+
+    // Affixes that look like signs or digits are only classified by position.
+    let options = FormatOptions::new().negative_affixes("-- ", " 0");
+    let chunks: Vec<_> = Approximint::new(-5).layout_chunks(&options).collect();
+    assert_eq!(
+        chunks,
+        [
+            (String::from("-- "), ChunkRole::Sign),
+            (String::from("5"), ChunkRole::Digits),
+            (String::from(" 0"), ChunkRole::Sign),
+        ]
+    );
+    let chunks: Vec<_> = Approximint::new(5).layout_chunks(&options).collect();
+    assert_eq!(chunks, [(String::from("5"), ChunkRole::Digits)]);
+}
@@ -0,0 +1,170 @@
+//! Vectors of [`Approximint`]s.
+//!
+//! These types are intended for representing positions and sizes in very
+//! large coordinate spaces. All operations are applied component-wise.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::Approximint;
+
+/// A two-dimensional vector of [`Approximint`]s.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct Vec2A {
+    /// The x component.
+    pub x: Approximint,
+    /// The y component.
+    pub y: Approximint,
+}
+
+impl Vec2A {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self::splat(Approximint::ZERO);
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self::splat(Approximint::ONE);
+
+    /// Returns a new vector from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: Approximint, y: Approximint) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns a new vector with all components set to `value`.
+    #[must_use]
+    #[inline]
+    pub const fn splat(value: Approximint) -> Self {
+        Self { x: value, y: value }
+    }
+
+    /// Returns a new vector with `z` added as the third component.
+    #[must_use]
+    #[inline]
+    pub const fn extend(self, z: Approximint) -> Vec3A {
+        Vec3A::new(self.x, self.y, z)
+    }
+}
+
+impl From<[Approximint; 2]> for Vec2A {
+    #[inline]
+    fn from([x, y]: [Approximint; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Vec2A> for [Approximint; 2] {
+    #[inline]
+    fn from(value: Vec2A) -> Self {
+        [value.x, value.y]
+    }
+}
+
+/// A three-dimensional vector of [`Approximint`]s.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct Vec3A {
+    /// The x component.
+    pub x: Approximint,
+    /// The y component.
+    pub y: Approximint,
+    /// The z component.
+    pub z: Approximint,
+}
+
+impl Vec3A {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self::splat(Approximint::ZERO);
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self::splat(Approximint::ONE);
+
+    /// Returns a new vector from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: Approximint, y: Approximint, z: Approximint) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns a new vector with all components set to `value`.
+    #[must_use]
+    #[inline]
+    pub const fn splat(value: Approximint) -> Self {
+        Self {
+            x: value,
+            y: value,
+            z: value,
+        }
+    }
+
+    /// Returns the x and y components of this vector.
+    #[must_use]
+    #[inline]
+    pub const fn truncate(self) -> Vec2A {
+        Vec2A::new(self.x, self.y)
+    }
+}
+
+impl From<[Approximint; 3]> for Vec3A {
+    #[inline]
+    fn from([x, y, z]: [Approximint; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Vec3A> for [Approximint; 3] {
+    #[inline]
+    fn from(value: Vec3A) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+/// Implements the arithmetic operators component-wise for a vector type.
+macro_rules! impl_vector_ops {
+    ($vector:ident { $($component:ident),+ }) => {
+        impl Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self { $($component: -self.$component),+ }
+            }
+        }
+
+        impl_vector_ops!(@op $vector { $($component),+ } Add::add, AddAssign::add_assign);
+        impl_vector_ops!(@op $vector { $($component),+ } Sub::sub, SubAssign::sub_assign);
+        impl_vector_ops!(@op $vector { $($component),+ } Mul::mul, MulAssign::mul_assign);
+
+        impl Mul<Approximint> for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Approximint) -> Self::Output {
+                Self { $($component: self.$component * rhs),+ }
+            }
+        }
+
+        impl MulAssign<Approximint> for $vector {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Approximint) {
+                *self = *self * rhs;
+            }
+        }
+    };
+    (@op $vector:ident { $($component:ident),+ } $op:ident::$method:ident, $assign:ident::$assign_method:ident) => {
+        impl $op for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self { $($component: $op::$method(self.$component, rhs.$component)),+ }
+            }
+        }
+
+        impl $assign for $vector {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vec2A { x, y });
+impl_vector_ops!(Vec3A { x, y, z });